@@ -0,0 +1,64 @@
+//! Borsh (de)serialization of `NearGas` as a `String` with its decimal number of gas,
+//! for interoperability with legacy tools that encode gas this way.
+//!
+//! The functions can be used with `#[borsh(serialize_with = "...", deserialize_with = "...")]`.
+//!
+//! # Examples
+//! ```
+//! use near_gas::NearGas;
+//!
+//! let mut buf = Vec::new();
+//! near_gas::borsh_string::serialize(&NearGas::from_gas(1000), &mut buf).unwrap();
+//! assert_eq!(buf, [4, 0, 0, 0, b'1', b'0', b'0', b'0']);
+//!
+//! let gas = near_gas::borsh_string::deserialize(&mut buf.as_slice()).unwrap();
+//! assert_eq!(gas, NearGas::from_gas(1000));
+//! ```
+use borsh::io::{Error, ErrorKind, Read, Result, Write};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::NearGas;
+
+/// Serializes `NearGas` as a borsh `String` of its decimal number of gas.
+pub fn serialize<W: Write>(gas: &NearGas, writer: &mut W) -> Result<()> {
+    gas.as_gas().to_string().serialize(writer)
+}
+
+/// Deserializes `NearGas` from a borsh `String` of its decimal number of gas.
+pub fn deserialize<R: Read>(reader: &mut R) -> Result<NearGas> {
+    let s = String::deserialize_reader(reader)?;
+    s.parse::<u64>()
+        .map(NearGas::from_gas)
+        .map_err(|err| Error::new(ErrorKind::InvalidData, err.to_string()))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::NearGas;
+
+    #[test]
+    fn borsh_string() {
+        fn test_borsh_string(val: u64, expected_serialized_value: &[u8]) {
+            let gas = NearGas::from_gas(val);
+            let mut ser = Vec::new();
+            super::serialize(&gas, &mut ser).unwrap();
+            assert_eq!(expected_serialized_value, ser.as_slice());
+            let de = super::deserialize(&mut ser.as_slice()).unwrap();
+            assert_eq!(de, gas);
+        }
+
+        test_borsh_string(0, &[1, 0, 0, 0, b'0']);
+        test_borsh_string(8, &[1, 0, 0, 0, b'8']);
+        test_borsh_string(1000, &[4, 0, 0, 0, b'1', b'0', b'0', b'0']);
+        let mut max = vec![20, 0, 0, 0];
+        max.extend_from_slice(b"18446744073709551615");
+        test_borsh_string(u64::MAX, &max);
+    }
+
+    #[test]
+    fn borsh_string_invalid() {
+        assert!(super::deserialize(&mut [2, 0, 0, 0, b'1', b'x'].as_slice()).is_err());
+        assert!(super::deserialize(&mut [1, 0, 0, 0, b'-'].as_slice()).is_err());
+        assert!(super::deserialize(&mut [5, 0, 0, 0, b'1'].as_slice()).is_err());
+    }
+}
@@ -1,14 +1,70 @@
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum NearGasError {
     IncorrectNumber(crate::utils::DecimalNumberParsingError),
-    IncorrectUnit(String),
+    IncorrectUnit {
+        input: String,
+        supported_units: &'static [&'static str],
+    },
+    ExceedsTransactionLimit(crate::NearGas),
+    InvalidListElement {
+        index: usize,
+        error: Box<NearGasError>,
+    },
+    InvalidVarint(Vec<u8>),
+    InvalidAssignment {
+        input: String,
+        key: String,
+    },
+    NotWholeTgas(crate::NearGas),
+    SumOverflow(String),
+}
+
+impl NearGasError {
+    pub(crate) fn incorrect_unit(input: &str) -> Self {
+        NearGasError::IncorrectUnit {
+            input: input.to_owned(),
+            supported_units: crate::NearGas::SUPPORTED_UNITS,
+        }
+    }
 }
 
 impl std::error::Error for NearGasError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             NearGasError::IncorrectNumber(err) => Some(err),
-            NearGasError::IncorrectUnit(_) => None,
+            NearGasError::IncorrectUnit { .. } => None,
+            NearGasError::ExceedsTransactionLimit(_) => None,
+            NearGasError::InvalidListElement { error, .. } => Some(error.as_ref()),
+            NearGasError::InvalidVarint(_) => None,
+            NearGasError::InvalidAssignment { .. } => None,
+            NearGasError::NotWholeTgas(_) => None,
+            NearGasError::SumOverflow(_) => None,
         }
     }
 }
+
+impl From<crate::utils::DecimalNumberParsingError> for NearGasError {
+    fn from(err: crate::utils::DecimalNumberParsingError) -> Self {
+        NearGasError::IncorrectNumber(err)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{DecimalNumberParsingError, NearGasError};
+
+    #[test]
+    fn from_decimal_number_parsing_error() {
+        fn parse() -> Result<(), NearGasError> {
+            Err(DecimalNumberParsingError::InvalidNumber("1h".to_owned()))?
+        }
+
+        assert_eq!(
+            parse(),
+            Err(NearGasError::IncorrectNumber(
+                DecimalNumberParsingError::InvalidNumber("1h".to_owned())
+            ))
+        );
+    }
+}
@@ -0,0 +1,20 @@
+/// Result of a fuzzy comparison of two gas values, see [`NearGas::compare_labeled`](crate::NearGas::compare_labeled).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GasComparison {
+    /// The gas is less than the other one by more than the tolerance.
+    Less,
+    /// The gas differs from the other one by no more than the tolerance.
+    Similar,
+    /// The gas is greater than the other one by more than the tolerance.
+    Greater,
+}
+
+impl std::fmt::Display for GasComparison {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GasComparison::Less => f.pad("less"),
+            GasComparison::Similar => f.pad("about the same"),
+            GasComparison::Greater => f.pad("more"),
+        }
+    }
+}
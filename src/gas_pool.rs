@@ -0,0 +1,178 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::NearGas;
+
+/// Source of unique [`GasPool`] ids, so that reservations can only be released to their pool.
+static NEXT_POOL_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// A pool of gas from which amounts can be reserved up front (e.g. for sub-calls)
+/// and released back once they turn out to be unused.
+///
+/// # Examples
+/// ```
+/// use near_gas::{GasPool, NearGas};
+///
+/// let mut pool = GasPool::new(NearGas::from_tgas(100));
+/// let reservation = pool.reserve(NearGas::from_tgas(60)).unwrap();
+/// assert!(pool.reserve(NearGas::from_tgas(60)).is_err());
+///
+/// pool.release(reservation).unwrap();
+/// assert_eq!(pool.available(), NearGas::from_tgas(100));
+/// ```
+///
+/// A clone is a snapshot of the same pool, so it accepts the reservations of the original.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasPool {
+    id: usize,
+    total: NearGas,
+    available: NearGas,
+}
+
+/// Gas reserved from a [`GasPool`], see [`GasPool::reserve`].
+#[derive(Debug, PartialEq, Eq)]
+#[must_use = "unused reservations should be released back to the pool"]
+pub struct Reservation {
+    pool_id: usize,
+    amount: NearGas,
+}
+
+/// Error returned by [`GasPool::reserve`] if the pool does not have enough gas left.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GasExhausted {
+    pub requested: NearGas,
+    pub available: NearGas,
+}
+
+/// Error returned by [`GasPool::release`] if the reservation was made by another pool.
+///
+/// The rejected reservation is handed back, so it can still be released to its own pool.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ForeignReservation {
+    pub reservation: Reservation,
+}
+
+impl GasPool {
+    /// Creates a pool with `total` gas available.
+    pub fn new(total: NearGas) -> Self {
+        GasPool {
+            id: NEXT_POOL_ID.fetch_add(1, Ordering::Relaxed),
+            total,
+            available: total,
+        }
+    }
+
+    /// Returns the gas the pool was created with.
+    pub const fn total(&self) -> NearGas {
+        self.total
+    }
+
+    /// Returns the gas that is not reserved.
+    pub const fn available(&self) -> NearGas {
+        self.available
+    }
+
+    /// Reserves `amount` of gas, returning `GasExhausted` if not enough gas is available.
+    pub fn reserve(&mut self, amount: NearGas) -> Result<Reservation, GasExhausted> {
+        let available = self.available.checked_sub(amount).ok_or(GasExhausted {
+            requested: amount,
+            available: self.available,
+        })?;
+        self.available = available;
+        Ok(Reservation {
+            pool_id: self.id,
+            amount,
+        })
+    }
+
+    /// Returns the reserved gas back to the pool, returning `ForeignReservation` if the
+    /// reservation was made by another pool.
+    pub fn release(&mut self, reservation: Reservation) -> Result<(), ForeignReservation> {
+        if reservation.pool_id != self.id {
+            return Err(ForeignReservation { reservation });
+        }
+        self.available = self.available.saturating_add(reservation.amount);
+        Ok(())
+    }
+}
+
+impl Reservation {
+    /// Returns the reserved gas.
+    pub const fn amount(&self) -> NearGas {
+        self.amount
+    }
+}
+
+impl std::fmt::Display for GasExhausted {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Gas exhausted: requested {}, available {}",
+            self.requested, self.available
+        )
+    }
+}
+
+impl std::error::Error for GasExhausted {}
+
+impl std::fmt::Display for ForeignReservation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Reservation of {} was made by another gas pool",
+            self.reservation.amount
+        )
+    }
+}
+
+impl std::error::Error for ForeignReservation {}
+
+#[cfg(test)]
+mod test {
+    use crate::{ForeignReservation, GasExhausted, GasPool, NearGas};
+
+    #[test]
+    fn reserve_beyond_capacity() {
+        let mut pool = GasPool::new(NearGas::from_tgas(100));
+        let first = pool.reserve(NearGas::from_tgas(70)).unwrap();
+        assert_eq!(first.amount(), NearGas::from_tgas(70));
+        assert_eq!(
+            pool.reserve(NearGas::from_tgas(31)),
+            Err(GasExhausted {
+                requested: NearGas::from_tgas(31),
+                available: NearGas::from_tgas(30),
+            })
+        );
+        assert_eq!(pool.available(), NearGas::from_tgas(30));
+        let second = pool.reserve(NearGas::from_tgas(30)).unwrap();
+        assert_eq!(pool.available(), NearGas::from_gas(0));
+        pool.release(first).unwrap();
+        pool.release(second).unwrap();
+    }
+
+    #[test]
+    fn release_back() {
+        let mut pool = GasPool::new(NearGas::from_tgas(100));
+        let reservation = pool.reserve(NearGas::from_tgas(60)).unwrap();
+        assert_eq!(pool.available(), NearGas::from_tgas(40));
+        assert_eq!(pool.release(reservation), Ok(()));
+        assert_eq!(pool.available(), NearGas::from_tgas(100));
+        assert_eq!(pool.total(), NearGas::from_tgas(100));
+    }
+
+    #[test]
+    fn release_foreign_reservation() {
+        let mut pool = GasPool::new(NearGas::from_tgas(100));
+        let own = pool.reserve(NearGas::from_tgas(60)).unwrap();
+        let mut other = GasPool::new(NearGas::from_tgas(100));
+        let foreign = other.reserve(NearGas::from_tgas(60)).unwrap();
+
+        let ForeignReservation { reservation } = pool.release(foreign).unwrap_err();
+        assert_eq!(pool.available(), NearGas::from_tgas(40));
+        assert!(pool.reserve(NearGas::from_tgas(60)).is_err());
+
+        assert_eq!(other.release(reservation), Ok(()));
+        assert_eq!(other.available(), NearGas::from_tgas(100));
+        assert_eq!(pool.release(own), Ok(()));
+        assert_eq!(pool.available(), NearGas::from_tgas(100));
+    }
+}
@@ -0,0 +1,83 @@
+use crate::NearGas;
+
+/// An inclusive range of gas `min..=max`, guaranteed to be non-empty.
+///
+/// # Examples
+/// ```
+/// use near_gas::{GasRange, NearGas};
+///
+/// let range = GasRange::new(NearGas::from_tgas(5), NearGas::from_tgas(30)).unwrap();
+/// assert!(range.contains(NearGas::from_tgas(10)));
+/// assert_eq!(range.clamp(NearGas::from_tgas(300)), NearGas::from_tgas(30));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GasRange {
+    min: NearGas,
+    max: NearGas,
+}
+
+impl GasRange {
+    /// Creates the range `min..=max`, returning None if `min` is greater than `max`.
+    pub const fn new(min: NearGas, max: NearGas) -> Option<Self> {
+        if min.as_gas() > max.as_gas() {
+            None
+        } else {
+            Some(GasRange { min, max })
+        }
+    }
+
+    /// Returns the lower bound of the range.
+    pub const fn min(&self) -> NearGas {
+        self.min
+    }
+
+    /// Returns the upper bound of the range.
+    pub const fn max(&self) -> NearGas {
+        self.max
+    }
+
+    /// Returns `true` if `gas` is within the range, bounds included.
+    pub const fn contains(&self, gas: NearGas) -> bool {
+        self.min.as_gas() <= gas.as_gas() && gas.as_gas() <= self.max.as_gas()
+    }
+
+    /// Restricts `gas` to the range.
+    pub fn clamp(&self, gas: NearGas) -> NearGas {
+        gas.clamp(self.min, self.max)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{GasRange, NearGas};
+
+    #[test]
+    fn new_range() {
+        let range = GasRange::new(NearGas::from_tgas(5), NearGas::from_tgas(30)).unwrap();
+        assert_eq!(range.min(), NearGas::from_tgas(5));
+        assert_eq!(range.max(), NearGas::from_tgas(30));
+        assert!(GasRange::new(NearGas::from_tgas(5), NearGas::from_tgas(5)).is_some());
+        assert_eq!(
+            GasRange::new(NearGas::from_tgas(30), NearGas::from_tgas(5)),
+            None
+        );
+    }
+
+    #[test]
+    fn contains_gas() {
+        let range = GasRange::new(NearGas::from_tgas(5), NearGas::from_tgas(30)).unwrap();
+        assert!(range.contains(NearGas::from_tgas(5)));
+        assert!(range.contains(NearGas::from_tgas(10)));
+        assert!(range.contains(NearGas::from_tgas(30)));
+        assert!(!range.contains(NearGas::from_gas(4_999_999_999_999)));
+        assert!(!range.contains(NearGas::from_gas(30_000_000_000_001)));
+    }
+
+    #[test]
+    fn clamp_gas() {
+        let range = GasRange::new(NearGas::from_tgas(5), NearGas::from_tgas(30)).unwrap();
+        assert_eq!(range.clamp(NearGas::from_gas(0)), NearGas::from_tgas(5));
+        assert_eq!(range.clamp(NearGas::from_tgas(10)), NearGas::from_tgas(10));
+        assert_eq!(range.clamp(NearGas::MAX), NearGas::from_tgas(30));
+    }
+}
@@ -0,0 +1,108 @@
+use crate::NearGas;
+
+/// Streaming aggregate of gas samples (count, min, max and sum) that does not store the samples.
+///
+/// # Examples
+/// ```
+/// use near_gas::{GasStats, NearGas};
+///
+/// let mut stats = GasStats::new();
+/// stats.record(NearGas::from_tgas(2));
+/// stats.record(NearGas::from_tgas(4));
+/// assert_eq!(stats.count(), 2);
+/// assert_eq!(stats.max(), Some(NearGas::from_tgas(4)));
+/// assert_eq!(stats.mean(), Some(NearGas::from_tgas(3)));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct GasStats {
+    count: u64,
+    min: Option<NearGas>,
+    max: Option<NearGas>,
+    sum: NearGas,
+}
+
+impl GasStats {
+    /// Creates empty stats. Same as `GasStats::default()`, but usable in const context.
+    pub const fn new() -> Self {
+        GasStats {
+            count: 0,
+            min: None,
+            max: None,
+            sum: NearGas::from_gas(0),
+        }
+    }
+
+    /// Records a gas sample.
+    pub fn record(&mut self, gas: NearGas) {
+        self.count += 1;
+        self.min = Some(self.min.map_or(gas, |min| min.min(gas)));
+        self.max = Some(self.max.map_or(gas, |max| max.max(gas)));
+        self.sum = self.sum.saturating_add(gas);
+    }
+
+    /// Returns the number of recorded samples.
+    pub const fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the smallest recorded sample, or None if nothing was recorded.
+    pub const fn min(&self) -> Option<NearGas> {
+        self.min
+    }
+
+    /// Returns the largest recorded sample, or None if nothing was recorded.
+    pub const fn max(&self) -> Option<NearGas> {
+        self.max
+    }
+
+    /// Returns the sum of the recorded samples, saturating at `NearGas::MAX`.
+    pub const fn sum(&self) -> NearGas {
+        self.sum
+    }
+
+    /// Returns the mean of the recorded samples rounded down, or None if nothing was recorded.
+    ///
+    /// The mean is computed from the saturating sum, so it is too low if the sum saturated.
+    pub const fn mean(&self) -> Option<NearGas> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(NearGas::from_gas(self.sum.as_gas() / self.count))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{GasStats, NearGas};
+
+    #[test]
+    fn record_sequence() {
+        let mut stats = GasStats::new();
+        assert_eq!(stats, GasStats::default());
+        assert_eq!(stats.count(), 0);
+        assert_eq!(stats.min(), None);
+        assert_eq!(stats.max(), None);
+        assert_eq!(stats.sum(), NearGas::from_gas(0));
+        assert_eq!(stats.mean(), None);
+
+        for tgas in [5, 1, 9, 3] {
+            stats.record(NearGas::from_tgas(tgas));
+        }
+        assert_eq!(stats.count(), 4);
+        assert_eq!(stats.min(), Some(NearGas::from_tgas(1)));
+        assert_eq!(stats.max(), Some(NearGas::from_tgas(9)));
+        assert_eq!(stats.sum(), NearGas::from_tgas(18));
+        assert_eq!(stats.mean(), Some(NearGas::from_ggas(4_500)));
+    }
+
+    #[test]
+    fn record_saturating_sum() {
+        let mut stats = GasStats::new();
+        stats.record(NearGas::MAX);
+        stats.record(NearGas::from_gas(1));
+        assert_eq!(stats.sum(), NearGas::MAX);
+        assert_eq!(stats.min(), Some(NearGas::from_gas(1)));
+        assert_eq!(stats.max(), Some(NearGas::MAX));
+    }
+}
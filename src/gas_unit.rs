@@ -0,0 +1,36 @@
+use crate::{ONE_GIGA_GAS, ONE_TERA_GAS};
+
+/// Units of gas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum GasUnit {
+    Gas,
+    Ggas,
+    Tgas,
+}
+
+impl GasUnit {
+    /// Returns the number of gas in one unit.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::GasUnit;
+    /// assert_eq!(GasUnit::Tgas.as_gas(), 1_000_000_000_000);
+    /// ```
+    pub const fn as_gas(self) -> u64 {
+        match self {
+            GasUnit::Gas => 1,
+            GasUnit::Ggas => ONE_GIGA_GAS,
+            GasUnit::Tgas => ONE_TERA_GAS,
+        }
+    }
+}
+
+impl std::fmt::Display for GasUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GasUnit::Gas => f.pad("gas"),
+            GasUnit::Ggas => f.pad("Ggas"),
+            GasUnit::Tgas => f.pad("Tgas"),
+        }
+    }
+}
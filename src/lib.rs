@@ -16,23 +16,70 @@
 //!
 //! * **borsh** (optional) -
 //!   When enabled allows `NearGas` to serialized and deserialized by `borsh`.
+//!   Also provides the `borsh_string` module to (de)serialize `NearGas` as a borsh `String` for legacy tools,
+//!   and `NearGas::read_borsh_slice` to decode a borsh-encoded `Vec<u64>` into `Vec<NearGas>` in one pass.
 //!
 //! * **serde** (optional) -
 //!   When enabled allows `NearGas` to serialized and deserialized by `serde`.
+//!   Also provides the `serde_tgas` module to (de)serialize `NearGas` as a decimal string of Tgas,
+//!   the `serde_tgas_number` module to (de)serialize it as a plain number of whole Tgas,
+//!   the `serde_u64` module to (de)serialize it as a plain `u64` (e.g. for compact binary formats like `postcard`),
+//!   the `serde_value_unit` module to (de)serialize it as an object with explicit value and unit,
+//!   the `serde_js_compact` module to serialize it as a number whenever it is safe for JavaScript,
+//!   and the `serde_byte_array` module to (de)serialize it as an array of 8 little-endian bytes.
+//!   `NearGasMap` wraps `NearGas` into a `{ "gas": ... }` map so it can be used with `#[serde(flatten)]`.
 //!
 //! * **schemars** (optional) -
 //!   Implements `schemars::JsonSchema` for `NearGas`.
+//!   Also provides `NearGas::integer_json_schema` to describe `NearGas` as a bounded integer.
 //!
 //! * **interactive-clap** (optional) -
 //!   Implements `interactive_clap::ToCli` for `NearGas`.
+//!
+//! * **serde_json** (optional) -
+//!   Implements `TryFrom<&serde_json::Number>` for `NearGas`.
+//!
+//! * **near-token** (optional) -
+//!   Provides helpers to reason about the cost of `NearGas` in `near_token::NearToken`.
+#[cfg(feature = "borsh")]
+pub mod borsh_string;
 mod error;
+mod gas_comparison;
+mod gas_pool;
+mod gas_range;
+mod gas_stats;
+mod gas_unit;
+mod macros;
+#[cfg(feature = "serde")]
+mod near_gas_map;
+mod relative_gas;
+#[cfg(feature = "serde")]
+pub mod serde_byte_array;
+#[cfg(feature = "serde")]
+pub mod serde_js_compact;
+#[cfg(feature = "serde")]
+pub mod serde_tgas;
+#[cfg(feature = "serde")]
+pub mod serde_tgas_number;
+#[cfg(feature = "serde")]
+pub mod serde_u64;
+#[cfg(feature = "serde")]
+pub mod serde_value_unit;
 mod trait_impls;
 mod utils;
 
 pub use self::error::NearGasError;
+pub use self::gas_comparison::GasComparison;
+pub use self::gas_pool::{ForeignReservation, GasExhausted, GasPool, Reservation};
+pub use self::gas_range::GasRange;
+pub use self::gas_stats::GasStats;
+pub use self::gas_unit::GasUnit;
+#[cfg(feature = "serde")]
+pub use self::near_gas_map::NearGasMap;
+pub use self::relative_gas::RelativeGas;
 pub use self::utils::DecimalNumberParsingError;
 
-#[derive(Default, Debug, Clone, Copy, PartialEq, PartialOrd, Ord, Eq, Hash)]
+#[derive(Default, Clone, Copy, PartialEq, PartialOrd, Ord, Eq, Hash)]
 #[cfg_attr(
     feature = "borsh",
     derive(borsh::BorshDeserialize, borsh::BorshSerialize)
@@ -45,8 +92,34 @@ pub struct NearGas {
 
 const ONE_TERA_GAS: u64 = 10u64.pow(12);
 const ONE_GIGA_GAS: u64 = 10u64.pow(9);
+const MAX_TRANSACTION_GAS: u64 = 300 * ONE_TERA_GAS;
+/// NEAR targets 1 Tgas of compute to take about 1 millisecond of wall-clock time.
+const GAS_PER_NANOSECOND: u64 = 10u64.pow(6);
+/// Protocol minimum gas price: 100 MegaYocto (10^8 yoctoNEAR) per gas, i.e. 0.0001 NEAR per Tgas.
+pub const MIN_GAS_PRICE_YOCTO: u128 = 100_000_000;
 
 impl NearGas {
+    /// The largest amount of gas that can be represented by `NearGas`.
+    pub const MAX: NearGas = NearGas::from_gas(u64::MAX);
+
+    /// Gas commonly kept aside for the callback of a cross-contract call, see [`NearGas::forward_after_reserve`].
+    pub const DEFAULT_CALLBACK_RESERVE: NearGas = NearGas::from_tgas(5);
+
+    /// Creates a new zero `NearGas`. Same as `NearGas::default()`, but usable in const context.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    ///
+    /// const NO_GAS: NearGas = NearGas::new();
+    ///
+    /// assert_eq!(NO_GAS, NearGas::default());
+    /// assert_eq!(NO_GAS.as_gas(), 0);
+    /// ```
+    pub const fn new() -> Self {
+        Self::from_gas(0)
+    }
+
     /// Creates a new `NearGas` from the specified number of whole tera Gas.
     ///
     /// # Examples
@@ -77,6 +150,22 @@ impl NearGas {
         Self { inner }
     }
 
+    /// Creates a new `NearGas` from the specified number of whole milli tera Gas (1/1000 Tgas),
+    /// which allows to express fractional Tgas in const context.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    ///
+    /// const ONE_AND_A_HALF_TERA_GAS: NearGas = NearGas::from_tgas_milli(1500);
+    ///
+    /// assert_eq!(ONE_AND_A_HALF_TERA_GAS, NearGas::from_ggas(1500));
+    /// ```
+    pub const fn from_tgas_milli(mut inner: u64) -> Self {
+        inner *= ONE_GIGA_GAS;
+        Self { inner }
+    }
+
     /// Creates a new `NearGas` from the specified number of whole Gas.
     ///
     /// # Examples
@@ -243,87 +332,2371 @@ impl NearGas {
         }
         NearGas::from_gas(self.as_gas().saturating_div(rhs))
     }
-}
 
-#[cfg(test)]
-mod test {
-    use crate::NearGas;
+    /// Computes the gas required for `bytes` of data at the given per-byte rate,
+    /// returning None if overflow occurred.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::for_bytes(10, NearGas::from_gas(5)), Some(NearGas::from_gas(50)));
+    /// assert_eq!(NearGas::for_bytes(2, NearGas::from_gas(u64::MAX)), None);
+    /// ```
+    pub const fn for_bytes(bytes: u64, gas_per_byte: NearGas) -> Option<NearGas> {
+        gas_per_byte.checked_mul(bytes)
+    }
 
-    #[test]
-    fn checked_add_gas() {
-        let gas = NearGas::from_gas(u64::MAX - 3);
-        let any_gas = NearGas::from_gas(3);
-        let more_gas = NearGas::from_gas(4);
-        assert_eq!(gas.checked_add(any_gas), Some(NearGas::from_gas(u64::MAX)));
-        assert_eq!(gas.checked_add(more_gas), None);
+    /// Wraps `self` into [`std::cmp::Reverse`] so it can be used as a key of a min-heap
+    /// built on top of [`std::collections::BinaryHeap`].
+    ///
+    /// `NearGas` is ordered strictly by its gas value.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// use std::collections::BinaryHeap;
+    ///
+    /// let mut heap = BinaryHeap::new();
+    /// heap.push(NearGas::from_tgas(5).min_heap_key());
+    /// heap.push(NearGas::from_tgas(1).min_heap_key());
+    /// heap.push(NearGas::from_tgas(3).min_heap_key());
+    /// assert_eq!(heap.pop().map(|gas| gas.0), Some(NearGas::from_tgas(1)));
+    /// ```
+    pub const fn min_heap_key(self) -> std::cmp::Reverse<NearGas> {
+        std::cmp::Reverse(self)
     }
 
-    #[test]
-    fn checked_sub_gas() {
-        let gas = NearGas::from_gas(3);
-        let any_gas = NearGas::from_gas(1);
-        let more_gas = NearGas::from_gas(4);
-        assert_eq!(gas.checked_sub(any_gas), Some(NearGas::from_gas(2)));
-        assert_eq!(gas.checked_sub(more_gas), None);
+    /// Validates that `self` fits into the 300 Tgas that can be attached to a single transaction,
+    /// returning `NearGasError::ExceedsTransactionLimit` otherwise.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{NearGas, NearGasError};
+    /// assert_eq!(NearGas::from_tgas(300).ensure_within_transaction_limit(), Ok(NearGas::from_tgas(300)));
+    /// assert_eq!(
+    ///     NearGas::from_tgas(301).ensure_within_transaction_limit(),
+    ///     Err(NearGasError::ExceedsTransactionLimit(NearGas::from_tgas(301)))
+    /// );
+    /// ```
+    pub fn ensure_within_transaction_limit(self) -> Result<NearGas, NearGasError> {
+        if self.as_gas() <= MAX_TRANSACTION_GAS {
+            Ok(self)
+        } else {
+            Err(NearGasError::ExceedsTransactionLimit(self))
+        }
     }
 
-    #[test]
-    fn checked_mul_gas() {
-        let gas = NearGas::from_gas(u64::MAX / 10);
-        assert_eq!(
-            gas.checked_mul(10),
-            Some(NearGas::from_gas(u64::MAX / 10 * 10))
-        );
-        assert_eq!(gas.checked_mul(11), None);
+    /// Computes `self` relative to `baseline` in basis points (1/10000), rounding down.
+    /// Returns None if `baseline` is zero or the result does not fit into `u64`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_tgas(5).basis_points_of(NearGas::from_tgas(5)), Some(10_000));
+    /// assert_eq!(NearGas::from_ggas(1).basis_points_of(NearGas::from_tgas(1)), Some(10));
+    /// assert_eq!(NearGas::from_tgas(1).basis_points_of(NearGas::from_gas(0)), None);
+    /// ```
+    pub const fn basis_points_of(self, baseline: NearGas) -> Option<u64> {
+        if baseline.as_gas() == 0 {
+            return None;
+        }
+        let bps = self.as_gas() as u128 * 10_000 / baseline.as_gas() as u128;
+        if bps > u64::MAX as u128 {
+            return None;
+        }
+        Some(bps as u64)
     }
 
-    #[test]
-    fn checked_div_gas() {
-        let gas = NearGas::from_gas(10);
-        assert_eq!(gas.checked_div(2), Some(NearGas::from_gas(5)));
-        assert_eq!(gas.checked_div(11), Some(NearGas::from_gas(0)));
-        assert_eq!(gas.checked_div(0), None);
+    /// Returns a reference to the total number of whole Gas contained by this `NearGas`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// let neargas = NearGas::from_gas(12345);
+    /// assert_eq!(neargas.as_inner_ref(), &12345);
+    /// ```
+    pub const fn as_inner_ref(&self) -> &u64 {
+        &self.inner
     }
 
-    #[test]
-    fn saturating_add_gas() {
-        let gas = NearGas::from_gas(100);
-        let added_gas = NearGas::from_gas(1);
-        let another_gas = NearGas::from_gas(u64::MAX);
-        assert_eq!(gas.saturating_add(added_gas), NearGas::from_gas(101));
-        assert_eq!(
-            another_gas.saturating_add(added_gas),
-            NearGas::from_gas(u64::MAX)
-        );
+    /// Merges two optional gas values, treating `None` as unspecified.
+    /// Returns the checked sum when both are present (None if overflow occurred),
+    /// the present value when only one is, and None when both are absent.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// let gas = NearGas::from_tgas(1);
+    /// assert_eq!(NearGas::merge_sum(Some(gas), Some(gas)), Some(NearGas::from_tgas(2)));
+    /// assert_eq!(NearGas::merge_sum(Some(gas), None), Some(gas));
+    /// assert_eq!(NearGas::merge_sum(None, None), None);
+    /// ```
+    pub const fn merge_sum(a: Option<NearGas>, b: Option<NearGas>) -> Option<NearGas> {
+        match (a, b) {
+            (Some(a), Some(b)) => a.checked_add(b),
+            (Some(gas), None) | (None, Some(gas)) => Some(gas),
+            (None, None) => None,
+        }
     }
 
-    #[test]
-    fn saturating_sub_gas() {
-        let gas = NearGas::from_gas(100);
-        let rhs_gas = NearGas::from_gas(1);
-        let another_gas = NearGas::from_gas(u64::MIN);
-        assert_eq!(gas.saturating_sub(rhs_gas), NearGas::from_gas(99));
-        assert_eq!(
-            another_gas.saturating_sub(rhs_gas),
-            NearGas::from_gas(u64::MIN)
-        );
+    /// Formats the value in Ggas regardless of its magnitude, keeping the exact fractional part
+    /// without trailing zeros.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_tgas(1).to_ggas_string(), "1000 Ggas");
+    /// assert_eq!(NearGas::from_gas(1_500_000_000).to_ggas_string(), "1.5 Ggas");
+    /// ```
+    pub fn to_ggas_string(self) -> String {
+        format!(
+            "{} Ggas",
+            utils::format_decimal_number(self.as_gas(), ONE_GIGA_GAS)
+        )
     }
 
-    #[test]
-    fn saturating_mul_gas() {
-        let gas = NearGas::from_gas(2);
-        let rhs = 10;
-        let another_gas = u64::MAX;
-        assert_eq!(gas.saturating_mul(rhs), NearGas::from_gas(20));
-        assert_eq!(gas.saturating_mul(another_gas), NearGas::from_gas(u64::MAX));
+    /// Checked integer subtraction. Computes self - rhs, returning the deficit (rhs - self) as an error
+    /// if overflow occurred.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_gas(5).checked_sub_verbose(NearGas::from_gas(2)), Ok(NearGas::from_gas(3)));
+    /// assert_eq!(NearGas::from_gas(2).checked_sub_verbose(NearGas::from_gas(5)), Err(NearGas::from_gas(3)));
+    /// ```
+    pub const fn checked_sub_verbose(self, rhs: NearGas) -> Result<NearGas, NearGas> {
+        if let Some(gas) = self.checked_sub(rhs) {
+            Ok(gas)
+        } else {
+            Err(NearGas::from_gas(rhs.as_gas() - self.as_gas()))
+        }
     }
 
-    #[test]
-    fn saturating_div_gas() {
-        let gas = NearGas::from_gas(10);
-        let rhs = 2;
-        let another_gas = 20;
-        assert_eq!(gas.saturating_div(rhs), NearGas::from_gas(5));
-        assert_eq!(gas.saturating_div(another_gas), NearGas::from_gas(0));
+    /// Returns the exact ratio of `self` to `other` as a reduced `(numerator, denominator)` fraction,
+    /// or None if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_tgas(6).ratio(NearGas::from_tgas(4)), Some((3, 2)));
+    /// assert_eq!(NearGas::from_tgas(6).ratio(NearGas::from_gas(0)), None);
+    /// ```
+    pub const fn ratio(self, other: NearGas) -> Option<(u64, u64)> {
+        if other.as_gas() == 0 {
+            return None;
+        }
+        let gcd = utils::gcd(self.as_gas(), other.as_gas());
+        Some((self.as_gas() / gcd, other.as_gas() / gcd))
+    }
+
+    /// Rounds the value down to the nearest whole giga Gas.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_gas(1_500_000_000).floor_to_ggas(), NearGas::from_ggas(1));
+    /// assert_eq!(NearGas::from_ggas(2).floor_to_ggas(), NearGas::from_ggas(2));
+    /// ```
+    pub const fn floor_to_ggas(self) -> NearGas {
+        NearGas::from_gas(self.as_gas() / ONE_GIGA_GAS * ONE_GIGA_GAS)
+    }
+
+    /// Rounds the value up to the nearest whole giga Gas, returning None if overflow occurred.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_gas(1_500_000_000).ceil_to_ggas(), Some(NearGas::from_ggas(2)));
+    /// assert_eq!(NearGas::from_ggas(2).ceil_to_ggas(), Some(NearGas::from_ggas(2)));
+    /// assert_eq!(NearGas::from_gas(u64::MAX).ceil_to_ggas(), None);
+    /// ```
+    pub const fn ceil_to_ggas(self) -> Option<NearGas> {
+        let floor = self.floor_to_ggas();
+        if floor.as_gas() == self.as_gas() {
+            Some(floor)
+        } else {
+            floor.checked_add(NearGas::from_ggas(1))
+        }
+    }
+
+    /// Returns the base 10 logarithm of the number of gas, rounded down, or None if the gas is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_tgas(1).log10_floor(), Some(12));
+    /// assert_eq!(NearGas::from_gas(0).log10_floor(), None);
+    /// ```
+    pub const fn log10_floor(self) -> Option<u32> {
+        self.as_gas().checked_ilog10()
+    }
+
+    /// Returns `true` if `self` and `other` differ by no more than `tolerance`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// let tolerance = NearGas::from_ggas(1);
+    /// assert!(NearGas::from_gas(5_000_000_000).approx_eq(NearGas::from_gas(6_000_000_000), tolerance));
+    /// assert!(NearGas::from_gas(6_000_000_000).approx_eq(NearGas::from_gas(5_000_000_000), tolerance));
+    /// assert!(!NearGas::from_gas(5_000_000_000).approx_eq(NearGas::from_gas(6_000_000_001), tolerance));
+    /// ```
+    pub const fn approx_eq(self, other: NearGas, tolerance: NearGas) -> bool {
+        self.as_gas().abs_diff(other.as_gas()) <= tolerance.as_gas()
+    }
+
+    /// Recovers the amount of gas from a total cost and a gas price, both in yoctoNEAR.
+    /// Returns None if `gas_price_yocto` is zero or the result does not fit into `u64`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// let gas_price = 100_000_000;
+    /// assert_eq!(NearGas::from_cost(500_000_000_000_000_000_000, gas_price), Some(NearGas::from_tgas(5)));
+    /// assert_eq!(NearGas::from_cost(1, 0), None);
+    /// ```
+    pub const fn from_cost(total_yocto: u128, gas_price_yocto: u128) -> Option<NearGas> {
+        if gas_price_yocto == 0 {
+            return None;
+        }
+        let gas = total_yocto / gas_price_yocto;
+        if gas > u64::MAX as u128 {
+            return None;
+        }
+        Some(NearGas::from_gas(gas as u64))
+    }
+
+    /// Returns `true` if the amount of gas is zero.
+    ///
+    /// Takes `&self` so it can be used with `#[serde(skip_serializing_if = "NearGas::is_zero")]`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert!(NearGas::from_gas(0).is_zero());
+    /// assert!(!NearGas::from_gas(1).is_zero());
+    /// ```
+    pub const fn is_zero(&self) -> bool {
+        self.inner == 0
+    }
+
+    /// Computes the refunded gas of a receipt, i.e. the part of `used` (the gas reserved for
+    /// the execution, e.g. prepaid gas) that was not `burnt`. Returns zero if `burnt` exceeds `used`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::refund(NearGas::from_tgas(30), NearGas::from_tgas(12)), NearGas::from_tgas(18));
+    /// assert_eq!(NearGas::refund(NearGas::from_tgas(12), NearGas::from_tgas(30)), NearGas::from_gas(0));
+    /// ```
+    pub const fn refund(used: NearGas, burnt: NearGas) -> NearGas {
+        used.saturating_sub(burnt)
+    }
+
+    /// Computes the percentage of `used` gas that was actually `burnt`,
+    /// returning None if `used` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::efficiency_percent(NearGas::from_tgas(30), NearGas::from_tgas(12)), Some(40.0));
+    /// assert_eq!(NearGas::efficiency_percent(NearGas::from_gas(0), NearGas::from_tgas(12)), None);
+    /// ```
+    pub fn efficiency_percent(used: NearGas, burnt: NearGas) -> Option<f64> {
+        if used.is_zero() {
+            return None;
+        }
+        Some(burnt.as_gas() as f64 / used.as_gas() as f64 * 100.0)
+    }
+
+    /// Consumes `amount` from `self`, returning the remaining gas together with the fraction
+    /// (`0.0..=1.0`) of `self` that has been used, or None if overflow occurred.
+    /// Consuming from a zero budget reports a fraction of `0.0`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_tgas(100).consume(NearGas::from_tgas(25)), Some((NearGas::from_tgas(75), 0.25)));
+    /// assert_eq!(NearGas::from_tgas(100).consume(NearGas::from_tgas(101)), None);
+    /// ```
+    pub fn consume(self, amount: NearGas) -> Option<(NearGas, f64)> {
+        let remaining = self.checked_sub(amount)?;
+        let fraction_used = if self.is_zero() {
+            0.0
+        } else {
+            amount.as_gas() as f64 / self.as_gas() as f64
+        };
+        Some((remaining, fraction_used))
+    }
+
+    /// Returns the minimum of `values`, or None if the slice is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::min_of(&[NearGas::from_tgas(2), NearGas::from_tgas(1)]), Some(NearGas::from_tgas(1)));
+    /// assert_eq!(NearGas::min_of(&[]), None);
+    /// ```
+    pub fn min_of(values: &[NearGas]) -> Option<NearGas> {
+        values.iter().min().copied()
+    }
+
+    /// Returns the maximum of `values`, or None if the slice is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::max_of(&[NearGas::from_tgas(2), NearGas::from_tgas(1)]), Some(NearGas::from_tgas(2)));
+    /// assert_eq!(NearGas::max_of(&[]), None);
+    /// ```
+    pub fn max_of(values: &[NearGas]) -> Option<NearGas> {
+        values.iter().max().copied()
+    }
+
+    /// Checked addition of whole tera Gas. Computes self + tgas Tgas, returning None if overflow occurred.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_tgas(1).checked_add_tgas(2), Some(NearGas::from_tgas(3)));
+    /// assert_eq!(NearGas::from_tgas(1).checked_add_tgas(u64::MAX), None);
+    /// ```
+    pub const fn checked_add_tgas(self, tgas: u64) -> Option<NearGas> {
+        if let Some(gas) = tgas.checked_mul(ONE_TERA_GAS) {
+            self.checked_add(NearGas::from_gas(gas))
+        } else {
+            None
+        }
+    }
+
+    /// Checked addition of whole giga Gas. Computes self + ggas Ggas, returning None if overflow occurred.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_tgas(1).checked_add_ggas(500), Some(NearGas::from_ggas(1500)));
+    /// assert_eq!(NearGas::from_tgas(1).checked_add_ggas(u64::MAX), None);
+    /// ```
+    pub const fn checked_add_ggas(self, ggas: u64) -> Option<NearGas> {
+        if let Some(gas) = ggas.checked_mul(ONE_GIGA_GAS) {
+            self.checked_add(NearGas::from_gas(gas))
+        } else {
+            None
+        }
+    }
+
+    /// Formats the value in Tgas with exactly `decimals` fractional digits and no unit suffix,
+    /// rounding half-up.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_ggas(1500).to_tgas_fixed(6), "1.500000");
+    /// assert_eq!(NearGas::from_ggas(1500).to_tgas_fixed(0), "2");
+    /// ```
+    pub fn to_tgas_fixed(self, decimals: usize) -> String {
+        const TGAS_DECIMALS: usize = 12;
+        if decimals >= TGAS_DECIMALS {
+            let fractional = format!("{:012}", self.as_gas() % ONE_TERA_GAS);
+            return format!("{}.{:0<decimals$}", self.as_tgas(), fractional);
+        }
+        let scale = 10u128.pow((TGAS_DECIMALS - decimals) as u32);
+        let rounded = (self.as_gas() as u128 + scale / 2) / scale;
+        if decimals == 0 {
+            return rounded.to_string();
+        }
+        let fractional_scale = 10u128.pow(decimals as u32);
+        format!(
+            "{}.{:0decimals$}",
+            rounded / fractional_scale,
+            rounded % fractional_scale
+        )
+    }
+
+    /// Computes the average of `self` and `other` weighted by their counts, rounding down.
+    /// Returns None if the total count is zero or overflow occurred.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(
+    ///     NearGas::from_tgas(1).weighted_merge(3, NearGas::from_tgas(5), 1),
+    ///     Some(NearGas::from_tgas(2))
+    /// );
+    /// assert_eq!(NearGas::from_tgas(1).weighted_merge(0, NearGas::from_tgas(5), 0), None);
+    /// ```
+    pub const fn weighted_merge(
+        self,
+        self_count: u64,
+        other: NearGas,
+        other_count: u64,
+    ) -> Option<NearGas> {
+        let total_count = self_count as u128 + other_count as u128;
+        if total_count == 0 {
+            return None;
+        }
+        let self_total = self.as_gas() as u128 * self_count as u128;
+        let other_total = other.as_gas() as u128 * other_count as u128;
+        if let Some(total) = self_total.checked_add(other_total) {
+            Some(NearGas::from_gas((total / total_count) as u64))
+        } else {
+            None
+        }
+    }
+
+    /// Saturating addition with a signed amount of gas. Computes self + delta,
+    /// saturating at the numeric bounds instead of overflowing.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_gas(5).saturating_add_signed(-2), NearGas::from_gas(3));
+    /// assert_eq!(NearGas::from_gas(5).saturating_add_signed(-10), NearGas::from_gas(0));
+    /// assert_eq!(NearGas::from_gas(u64::MAX).saturating_add_signed(1), NearGas::from_gas(u64::MAX));
+    /// ```
+    pub const fn saturating_add_signed(self, delta: i64) -> NearGas {
+        NearGas::from_gas(self.as_gas().saturating_add_signed(delta))
+    }
+
+    /// Formats the value in Tgas with the `" TGas"` suffix expected by NEAR CLI,
+    /// keeping the exact fractional part without trailing zeros.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_tgas(30).to_cli_arg(), "30 TGas");
+    /// assert_eq!(NearGas::from_ggas(1500).to_cli_arg(), "1.5 TGas");
+    /// ```
+    pub fn to_cli_arg(self) -> String {
+        format!(
+            "{} TGas",
+            utils::format_decimal_number(self.as_gas(), ONE_TERA_GAS)
+        )
+    }
+
+    /// Creates a new `NearGas` from its little-endian byte representation (as used by borsh).
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_le_bytes([8, 0, 0, 0, 0, 0, 0, 0]), NearGas::from_gas(8));
+    /// ```
+    pub const fn from_le_bytes(bytes: [u8; 8]) -> Self {
+        NearGas::from_gas(u64::from_le_bytes(bytes))
+    }
+
+    /// Returns the little-endian byte representation (as used by borsh) of this `NearGas`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_gas(8).to_le_bytes(), [8, 0, 0, 0, 0, 0, 0, 0]);
+    /// ```
+    pub const fn to_le_bytes(self) -> [u8; 8] {
+        self.as_gas().to_le_bytes()
+    }
+
+    /// Returns the number of decimal digits in the number of gas (zero has one digit).
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_gas(0).num_digits(), 1);
+    /// assert_eq!(NearGas::from_gas(7).num_digits(), 1);
+    /// assert_eq!(NearGas::from_tgas(300).num_digits(), 15);
+    /// assert_eq!(NearGas::from_gas(u64::MAX).num_digits(), 20);
+    /// ```
+    pub const fn num_digits(self) -> u32 {
+        if let Some(log) = self.log10_floor() {
+            log + 1
+        } else {
+            1
+        }
+    }
+
+    /// Computes self * mul / div without intermediate overflow, rounding down.
+    /// Returns None if `div` is zero or the result does not fit into `u64`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_gas(u64::MAX).mul_div(2, 4), Some(NearGas::from_gas(u64::MAX / 2)));
+    /// assert_eq!(NearGas::from_gas(u64::MAX).mul_div(2, 1), None);
+    /// assert_eq!(NearGas::from_gas(10).mul_div(2, 0), None);
+    /// ```
+    pub const fn mul_div(self, mul: u64, div: u64) -> Option<NearGas> {
+        if div == 0 {
+            return None;
+        }
+        let gas = self.as_gas() as u128 * mul as u128 / div as u128;
+        if gas > u64::MAX as u128 {
+            return None;
+        }
+        Some(NearGas::from_gas(gas as u64))
+    }
+
+    /// Compares `self` with `other`, treating values that differ by no more than `tolerance` as similar.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{GasComparison, NearGas};
+    /// let tolerance = NearGas::from_tgas(1);
+    /// assert_eq!(NearGas::from_tgas(5).compare_labeled(NearGas::from_tgas(10), tolerance), GasComparison::Less);
+    /// assert_eq!(NearGas::from_tgas(10).compare_labeled(NearGas::from_tgas(9), tolerance), GasComparison::Similar);
+    /// assert_eq!(NearGas::from_tgas(10).compare_labeled(NearGas::from_tgas(5), tolerance), GasComparison::Greater);
+    /// ```
+    pub const fn compare_labeled(self, other: NearGas, tolerance: NearGas) -> GasComparison {
+        if self.approx_eq(other, tolerance) {
+            GasComparison::Similar
+        } else if self.as_gas() < other.as_gas() {
+            GasComparison::Less
+        } else {
+            GasComparison::Greater
+        }
+    }
+
+    /// Computes the running totals of `values`, returning None if overflow occurred.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(
+    ///     NearGas::cumulative(&[NearGas::from_tgas(1), NearGas::from_tgas(2), NearGas::from_tgas(3)]),
+    ///     Some(vec![NearGas::from_tgas(1), NearGas::from_tgas(3), NearGas::from_tgas(6)])
+    /// );
+    /// ```
+    pub fn cumulative(values: &[NearGas]) -> Option<Vec<NearGas>> {
+        let mut total = NearGas::from_gas(0);
+        values
+            .iter()
+            .map(|gas| {
+                total = total.checked_add(*gas)?;
+                Some(total)
+            })
+            .collect()
+    }
+
+    /// Checked sum of `values`, returning the index of the element at which overflow occurred.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::checked_sum_indexed(&[NearGas::from_tgas(1), NearGas::from_tgas(2)]), Ok(NearGas::from_tgas(3)));
+    /// assert_eq!(NearGas::checked_sum_indexed(&[NearGas::from_tgas(1), NearGas::from_gas(u64::MAX)]), Err(1));
+    /// ```
+    pub fn checked_sum_indexed(values: &[NearGas]) -> Result<NearGas, usize> {
+        values
+            .iter()
+            .enumerate()
+            .try_fold(NearGas::from_gas(0), |total, (index, gas)| {
+                total.checked_add(*gas).ok_or(index)
+            })
+    }
+
+    /// Returns the cost of this amount of gas in yoctoNEAR at the protocol minimum gas price
+    /// of 100 MegaYocto (10^8 yoctoNEAR) per gas, i.e. 1 Tgas costs 0.0001 NEAR.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// // 0.0001 NEAR
+    /// assert_eq!(NearGas::from_tgas(1).min_cost_near(), 100_000_000_000_000_000_000);
+    /// ```
+    pub const fn min_cost_near(self) -> u128 {
+        self.cost_at_min_price()
+    }
+
+    /// Returns the cost of this amount of gas in yoctoNEAR at [`MIN_GAS_PRICE_YOCTO`].
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{NearGas, MIN_GAS_PRICE_YOCTO};
+    /// assert_eq!(NearGas::from_gas(5).cost_at_min_price(), 5 * MIN_GAS_PRICE_YOCTO);
+    /// ```
+    pub const fn cost_at_min_price(self) -> u128 {
+        self.as_gas() as u128 * MIN_GAS_PRICE_YOCTO
+    }
+
+    /// Returns `self` as a multiple of `base`, or None if `base` is zero.
+    /// See [`NearGas::ratio`] for the exact fraction.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_ggas(3500).as_multiple_of(NearGas::from_tgas(1)), Some(3.5));
+    /// assert_eq!(NearGas::from_tgas(1).as_multiple_of(NearGas::from_gas(0)), None);
+    /// ```
+    pub fn as_multiple_of(self, base: NearGas) -> Option<f64> {
+        if base.is_zero() {
+            return None;
+        }
+        Some(self.as_gas() as f64 / base.as_gas() as f64)
+    }
+
+    /// Returns the approximate wall-clock time needed to burn this amount of gas,
+    /// based on the protocol target of 1 Tgas ≈ 1 millisecond (rounded down to whole nanoseconds).
+    ///
+    /// This is only an estimate for profiling output: actual execution time depends on the
+    /// hardware and on how well gas costs reflect the operations performed.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// use std::time::Duration;
+    /// assert_eq!(NearGas::from_tgas(1).approx_duration(), Duration::from_millis(1));
+    /// assert_eq!(NearGas::from_tgas(300).approx_duration(), Duration::from_millis(300));
+    /// ```
+    pub const fn approx_duration(self) -> std::time::Duration {
+        std::time::Duration::from_nanos(self.as_gas() / GAS_PER_NANOSECOND)
+    }
+
+    /// Converts an estimated wall-clock time into gas, based on the protocol target
+    /// of 1 Tgas ≈ 1 millisecond (see [`NearGas::approx_duration`]), saturating at [`NearGas::MAX`].
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// use std::time::Duration;
+    /// assert_eq!(NearGas::from_approx_duration(Duration::from_millis(1)), NearGas::from_tgas(1));
+    /// assert_eq!(NearGas::from_approx_duration(Duration::MAX), NearGas::MAX);
+    /// ```
+    pub const fn from_approx_duration(duration: std::time::Duration) -> NearGas {
+        let gas = duration
+            .as_nanos()
+            .saturating_mul(GAS_PER_NANOSECOND as u128);
+        if gas > u64::MAX as u128 {
+            NearGas::MAX
+        } else {
+            NearGas::from_gas(gas as u64)
+        }
+    }
+
+    /// Returns `true` if self + rhs does not overflow.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert!(NearGas::from_gas(u64::MAX - 1).can_add(NearGas::from_gas(1)));
+    /// assert!(!NearGas::from_gas(u64::MAX - 1).can_add(NearGas::from_gas(2)));
+    /// assert!(NearGas::MAX.can_add(NearGas::from_gas(0)));
+    /// ```
+    pub const fn can_add(self, rhs: NearGas) -> bool {
+        self.as_gas().checked_add(rhs.as_gas()).is_some()
+    }
+
+    /// Computes self * numerator / denominator without intermediate overflow, rounding down and
+    /// saturating at [`NearGas::MAX`] instead of overflowing. A zero `denominator` also yields [`NearGas::MAX`].
+    /// See [`NearGas::mul_div`] for the checked version.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_tgas(300).saturating_mul_ratio(2, 3), NearGas::from_tgas(200));
+    /// assert_eq!(NearGas::MAX.saturating_mul_ratio(3, 2), NearGas::MAX);
+    /// assert_eq!(NearGas::from_tgas(1).saturating_mul_ratio(1, 0), NearGas::MAX);
+    /// ```
+    pub const fn saturating_mul_ratio(self, numerator: u64, denominator: u64) -> NearGas {
+        if let Some(gas) = self.mul_div(numerator, denominator) {
+            gas
+        } else {
+            NearGas::MAX
+        }
+    }
+
+    /// Formats the number of gas as a compact SI-style string with K/M/G/T suffixes and at most
+    /// one decimal (rounded half-up), e.g. `"300G"` or `"1.5T"`. Values below 1000 are printed as is.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_ggas(300).to_si_string(), "300G");
+    /// assert_eq!(NearGas::from_ggas(1500).to_si_string(), "1.5T");
+    /// assert_eq!(NearGas::from_gas(42).to_si_string(), "42");
+    /// ```
+    pub fn to_si_string(self) -> String {
+        if self.as_gas() < 1000 {
+            return self.as_gas().to_string();
+        }
+        const SUFFIXES: [(&str, u128); 4] = [
+            ("K", 10u128.pow(3)),
+            ("M", 10u128.pow(6)),
+            ("G", 10u128.pow(9)),
+            ("T", 10u128.pow(12)),
+        ];
+        let tenths = |scale: u128| (self.as_gas() as u128 * 10 + scale / 2) / scale;
+        // Rounding may carry the value over to the next suffix (e.g. 999.95K is 1M),
+        // and the largest suffix is used for everything beyond it.
+        let (suffix, scale) = SUFFIXES
+            .iter()
+            .find(|(_, scale)| tenths(*scale) < 10_000)
+            .unwrap_or(&SUFFIXES[SUFFIXES.len() - 1]);
+        let tenths = tenths(*scale);
+        if tenths % 10 == 0 {
+            format!("{}{}", tenths / 10, suffix)
+        } else {
+            format!("{}.{}{}", tenths / 10, tenths % 10, suffix)
+        }
+    }
+
+    /// Returns the largest unit that divides the value exactly, so it can be displayed without decimals.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{GasUnit, NearGas};
+    /// assert_eq!(NearGas::from_tgas(5).natural_unit(), GasUnit::Tgas);
+    /// assert_eq!(NearGas::from_ggas(1500).natural_unit(), GasUnit::Ggas);
+    /// assert_eq!(NearGas::from_gas(1_500_000_001).natural_unit(), GasUnit::Gas);
+    /// ```
+    pub const fn natural_unit(self) -> GasUnit {
+        if self.as_gas() % ONE_TERA_GAS == 0 {
+            GasUnit::Tgas
+        } else if self.as_gas() % ONE_GIGA_GAS == 0 {
+            GasUnit::Ggas
+        } else {
+            GasUnit::Gas
+        }
+    }
+
+    /// Calculates the midpoint (self + other) / 2 without overflow, rounding down.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_tgas(1).midpoint(NearGas::from_tgas(2)), NearGas::from_ggas(1500));
+    /// assert_eq!(NearGas::from_gas(3).midpoint(NearGas::from_gas(0)), NearGas::from_gas(1));
+    /// assert_eq!(NearGas::MAX.midpoint(NearGas::from_gas(u64::MAX - 2)), NearGas::from_gas(u64::MAX - 1));
+    /// ```
+    pub const fn midpoint(self, other: NearGas) -> NearGas {
+        let (low, high) = if self.as_gas() < other.as_gas() {
+            (self.as_gas(), other.as_gas())
+        } else {
+            (other.as_gas(), self.as_gas())
+        };
+        NearGas::from_gas(low + (high - low) / 2)
+    }
+
+    /// Encodes the number of gas as unsigned LEB128 (varint), using 1 to 10 bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_gas(127).to_varint(), [0x7f]);
+    /// assert_eq!(NearGas::from_gas(300).to_varint(), [0xac, 0x02]);
+    /// ```
+    pub fn to_varint(self) -> Vec<u8> {
+        let mut value = self.as_gas();
+        let mut bytes = Vec::with_capacity(10);
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if value == 0 {
+                bytes.push(byte);
+                return bytes;
+            }
+            bytes.push(byte | 0x80);
+        }
+    }
+
+    /// Decodes the number of gas from unsigned LEB128 (varint) at the start of `bytes`,
+    /// returning it together with the number of bytes consumed.
+    ///
+    /// Returns `NearGasError::InvalidVarint` if the input ends before the varint does,
+    /// or if the encoded value does not fit into `u64`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_varint(&[0xac, 0x02, 0xff]), Ok((NearGas::from_gas(300), 2)));
+    /// assert!(NearGas::from_varint(&[0xac]).is_err());
+    /// ```
+    pub fn from_varint(bytes: &[u8]) -> Result<(NearGas, usize), NearGasError> {
+        let mut value = 0u64;
+        for (index, byte) in bytes.iter().enumerate().take(10) {
+            let part = u64::from(byte & 0x7f);
+            // The 10th byte may only carry the single highest bit of `u64`.
+            if index == 9 && part > 1 {
+                break;
+            }
+            value |= part << (7 * index);
+            if byte & 0x80 == 0 {
+                return Ok((NearGas::from_gas(value), index + 1));
+            }
+        }
+        Err(NearGasError::InvalidVarint(
+            bytes.iter().take(10).copied().collect(),
+        ))
+    }
+
+    /// Rounds the number of gas to `figs` significant decimal digits, rounding half up.
+    ///
+    /// Zero stays zero, and `figs == 0` always returns zero. If rounding up does not fit
+    /// into `u64` (e.g. `NearGas::MAX` to one significant digit), the result saturates at
+    /// `NearGas::MAX`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// let gas = NearGas::from_gas(3_456_000_000_000);
+    /// assert_eq!(gas.round_sig_figs(2), NearGas::from_gas(3_500_000_000_000));
+    /// assert_eq!(gas.round_sig_figs(3), NearGas::from_gas(3_460_000_000_000));
+    /// ```
+    pub fn round_sig_figs(self, figs: u32) -> NearGas {
+        if figs == 0 {
+            return NearGas::from_gas(0);
+        }
+        let digits = self.num_digits();
+        if figs >= digits {
+            return self;
+        }
+        let scale = 10u128.pow(digits - figs);
+        let rounded = (self.as_gas() as u128 + scale / 2) / scale * scale;
+        NearGas::from_gas(rounded.min(u64::MAX as u128) as u64)
+    }
+
+    /// Returns the whole number of tera Gas together with the remaining whole giga Gas.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_ggas(3_250).tgas_and_ggas(), (3, 250));
+    /// assert_eq!(NearGas::from_tgas(5).tgas_and_ggas(), (5, 0));
+    /// ```
+    pub const fn tgas_and_ggas(self) -> (u64, u64) {
+        (
+            self.inner / ONE_TERA_GAS,
+            (self.inner % ONE_TERA_GAS) / ONE_GIGA_GAS,
+        )
+    }
+
+    /// Computes the percentage of gas saved by the `new` implementation compared to the `old` one,
+    /// returning None if `old` is zero. The result is negative if `new` uses more gas.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::savings_percent(NearGas::from_tgas(20), NearGas::from_tgas(15)), Some(25.0));
+    /// assert_eq!(NearGas::savings_percent(NearGas::from_gas(0), NearGas::from_tgas(15)), None);
+    /// ```
+    pub fn savings_percent(old: NearGas, new: NearGas) -> Option<f64> {
+        if old.is_zero() {
+            return None;
+        }
+        let old = old.as_gas() as f64;
+        Some((old - new.as_gas() as f64) / old * 100.0)
+    }
+
+    /// Computes the refunded gas as `prepaid - used`, returning None if `used` exceeds `prepaid`.
+    ///
+    /// Unlike [`NearGas::refund`], which clamps to zero, this surfaces the invariant violation.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::checked_refund(NearGas::from_tgas(30), NearGas::from_tgas(12)), Some(NearGas::from_tgas(18)));
+    /// assert_eq!(NearGas::checked_refund(NearGas::from_tgas(12), NearGas::from_tgas(30)), None);
+    /// ```
+    pub const fn checked_refund(prepaid: NearGas, used: NearGas) -> Option<NearGas> {
+        prepaid.checked_sub(used)
+    }
+
+    /// Converts the number of gas to tera Gas as `f64`, together with a flag that is set if the
+    /// number of gas exceeds `2^53` and thus cannot be represented exactly by `f64`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_ggas(1500).to_tgas_f64_checked(), (1.5, false));
+    /// assert!(NearGas::from_gas(u64::MAX).to_tgas_f64_checked().1);
+    /// ```
+    pub fn to_tgas_f64_checked(self) -> (f64, bool) {
+        const MAX_EXACT_GAS: u64 = 1 << 53;
+        (
+            self.as_gas() as f64 / ONE_TERA_GAS as f64,
+            self.as_gas() > MAX_EXACT_GAS,
+        )
+    }
+
+    /// Returns `true` if `self` is equal to `other`, usable in const contexts.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// const _: () = assert!(NearGas::from_tgas(1).eq_const(NearGas::from_ggas(1000)));
+    /// ```
+    pub const fn eq_const(self, other: NearGas) -> bool {
+        self.inner == other.inner
+    }
+
+    /// Returns `true` if `self` is less than `other`, usable in const contexts.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// const _: () = assert!(NearGas::from_ggas(999).lt_const(NearGas::from_tgas(1)));
+    /// ```
+    pub const fn lt_const(self, other: NearGas) -> bool {
+        self.inner < other.inner
+    }
+
+    /// Returns `true` if `self` is less than or equal to `other`, usable in const contexts.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// const _: () = assert!(NearGas::from_tgas(1).le_const(NearGas::from_ggas(1000)));
+    /// ```
+    pub const fn le_const(self, other: NearGas) -> bool {
+        self.inner <= other.inner
+    }
+
+    /// Computes the gas that can be forwarded to a cross-contract call after keeping `reserve`
+    /// for the callback, i.e. `prepaid - reserve`, or zero if `prepaid` is below `reserve`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(
+    ///     NearGas::forward_after_reserve(NearGas::from_tgas(30), NearGas::DEFAULT_CALLBACK_RESERVE),
+    ///     NearGas::from_tgas(25)
+    /// );
+    /// ```
+    pub const fn forward_after_reserve(prepaid: NearGas, reserve: NearGas) -> NearGas {
+        prepaid.saturating_sub(reserve)
+    }
+
+    /// Compares only the whole number of tera Gas, ignoring any sub-Tgas remainder.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// use std::cmp::Ordering;
+    /// assert_eq!(NearGas::from_ggas(5_100).cmp_tgas(NearGas::from_ggas(5_900)), Ordering::Equal);
+    /// assert_eq!(NearGas::from_ggas(5_900).cmp_tgas(NearGas::from_tgas(6)), Ordering::Less);
+    /// ```
+    pub fn cmp_tgas(self, other: NearGas) -> std::cmp::Ordering {
+        self.as_tgas().cmp(&other.as_tgas())
+    }
+
+    /// Computes the percentage of the maximum transaction gas (300 Tgas) taken by `self`.
+    /// Values above the limit are reported as is, i.e. above 100%.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_tgas(120).percent_of_transaction_limit(), 40.0);
+    /// assert_eq!(NearGas::from_tgas(600).percent_of_transaction_limit(), 200.0);
+    /// ```
+    pub fn percent_of_transaction_limit(self) -> f64 {
+        self.as_gas() as f64 / MAX_TRANSACTION_GAS as f64 * 100.0
+    }
+
+    /// Computes the geometric mean of `values` as `exp(mean(ln(x)))`, rounded to the nearest gas.
+    /// Returns None if the slice is empty or contains zero.
+    ///
+    /// The computation is done in `f64`, so the result may be off by a few gas for large values.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// let mean = NearGas::geometric_mean(&[NearGas::from_tgas(1), NearGas::from_tgas(4)]).unwrap();
+    /// assert!(mean.as_gas().abs_diff(NearGas::from_tgas(2).as_gas()) < 1_000);
+    /// assert_eq!(NearGas::geometric_mean(&[]), None);
+    /// ```
+    pub fn geometric_mean(values: &[NearGas]) -> Option<NearGas> {
+        if values.is_empty() || values.iter().any(NearGas::is_zero) {
+            return None;
+        }
+        let mean_ln = values
+            .iter()
+            .map(|value| (value.as_gas() as f64).ln())
+            .sum::<f64>()
+            / values.len() as f64;
+        // `as` saturates at `u64::MAX` if rounding pushes the mean past it.
+        Some(NearGas::from_gas(mean_ln.exp().round() as u64))
+    }
+
+    /// Computes the signed difference of `self` from `baseline` in gas, together with
+    /// the percent change relative to `baseline`, which is None if `baseline` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(
+    ///     NearGas::from_tgas(15).diff(NearGas::from_tgas(20)),
+    ///     (-5_000_000_000_000, Some(-25.0))
+    /// );
+    /// ```
+    pub fn diff(self, baseline: NearGas) -> (i128, Option<f64>) {
+        let delta = self.as_gas() as i128 - baseline.as_gas() as i128;
+        let percent = if baseline.is_zero() {
+            None
+        } else {
+            Some(delta as f64 / baseline.as_gas() as f64 * 100.0)
+        };
+        (delta, percent)
+    }
+
+    /// Returns the number of tera Gas, or `NearGasError::NotWholeTgas` if `self` is not
+    /// an exact multiple of 1 Tgas.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{NearGas, NearGasError};
+    /// assert_eq!(NearGas::from_tgas(30).require_whole_tgas(), Ok(30));
+    /// assert_eq!(
+    ///     NearGas::from_ggas(1500).require_whole_tgas(),
+    ///     Err(NearGasError::NotWholeTgas(NearGas::from_ggas(1500)))
+    /// );
+    /// ```
+    pub fn require_whole_tgas(self) -> Result<u64, NearGasError> {
+        if self.as_gas() % ONE_TERA_GAS == 0 {
+            Ok(self.as_tgas())
+        } else {
+            Err(NearGasError::NotWholeTgas(self))
+        }
+    }
+
+    /// Generates `steps` gas budgets growing geometrically from `base` by `factor`,
+    /// i.e. `[base, base * factor, base * factor^2, ...]`, saturating at `NearGas::MAX`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(
+    ///     NearGas::backoff_schedule(NearGas::from_tgas(5), 2, 3),
+    ///     vec![NearGas::from_tgas(5), NearGas::from_tgas(10), NearGas::from_tgas(20)]
+    /// );
+    /// ```
+    pub fn backoff_schedule(base: NearGas, factor: u64, steps: u32) -> Vec<NearGas> {
+        std::iter::successors(Some(base), |gas| Some(gas.saturating_mul(factor)))
+            .take(steps as usize)
+            .collect()
+    }
+
+    /// Returns how much gas can still be added to `self` before overflowing `u64`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_gas(0).headroom(), NearGas::MAX);
+    /// assert_eq!(NearGas::from_gas(u64::MAX - 5).headroom(), NearGas::from_gas(5));
+    /// const _: () = assert!(NearGas::MAX.headroom().is_zero());
+    /// ```
+    pub const fn headroom(self) -> NearGas {
+        NearGas::from_gas(u64::MAX - self.inner)
+    }
+
+    /// Returns `true` if `self` and `other` differ by no more than `percent` of the larger one.
+    /// Two zero values are always considered equal.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert!(NearGas::from_tgas(100).within_percent(NearGas::from_tgas(96), 5.0));
+    /// assert!(!NearGas::from_tgas(100).within_percent(NearGas::from_tgas(94), 5.0));
+    /// ```
+    pub fn within_percent(self, other: NearGas, percent: f64) -> bool {
+        let max = self.max(other);
+        if max.is_zero() {
+            return true;
+        }
+        self.as_gas().abs_diff(other.as_gas()) as f64 / max.as_gas() as f64 * 100.0 <= percent
+    }
+
+    /// Formats the gas as a decimal number of `unit` without the unit itself
+    /// and without trailing zeros, e.g. `"1.5"` for 1500 Ggas in Tgas.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{GasUnit, NearGas};
+    /// assert_eq!(NearGas::from_ggas(1500).to_string_in(GasUnit::Tgas), "1.5");
+    /// assert_eq!(NearGas::from_ggas(1500).to_string_in(GasUnit::Ggas), "1500");
+    /// ```
+    pub fn to_string_in(self, unit: GasUnit) -> String {
+        crate::utils::format_decimal_number(self.as_gas(), unit.as_gas())
+    }
+
+    /// Parses a decimal number without a unit (e.g. `"1.5"`) as an amount of `unit`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::{GasUnit, NearGas};
+    /// assert_eq!(NearGas::from_string_in("1.5", GasUnit::Tgas), Ok(NearGas::from_ggas(1500)));
+    /// assert!(NearGas::from_string_in("1.5 Tgas", GasUnit::Tgas).is_err());
+    /// ```
+    pub fn from_string_in(s: &str, unit: GasUnit) -> Result<NearGas, NearGasError> {
+        Ok(NearGas::from_gas(crate::utils::parse_decimal_number(
+            s,
+            unit.as_gas(),
+        )?))
+    }
+
+    /// Sorts `values` and returns their `p`-th percentile (`0.0..=100.0`), linearly
+    /// interpolating between the nearest ranks and rounding to the nearest gas.
+    /// Returns None if the slice is empty or `p` is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// let mut values = [NearGas::from_tgas(4), NearGas::from_tgas(1), NearGas::from_tgas(2)];
+    /// assert_eq!(NearGas::percentile(&mut values, 50.0), Some(NearGas::from_tgas(2)));
+    /// assert_eq!(NearGas::percentile(&mut values, 75.0), Some(NearGas::from_tgas(3)));
+    /// ```
+    pub fn percentile(values: &mut [NearGas], p: f64) -> Option<NearGas> {
+        if values.is_empty() || !(0.0..=100.0).contains(&p) {
+            return None;
+        }
+        values.sort_unstable();
+        let rank = p / 100.0 * (values.len() - 1) as f64;
+        let lower = values[rank.floor() as usize].as_gas();
+        let upper = values[rank.ceil() as usize].as_gas();
+        let offset = ((upper - lower) as f64 * rank.fract()).round() as u64;
+        Some(NearGas::from_gas(lower + offset.min(upper - lower)))
+    }
+
+    /// Splits `self` into `n` parts of at least `floor` each, distributing the rest evenly
+    /// (the first parts get one gas more if it does not divide evenly), so that the parts
+    /// sum up to `self`. Returns None if `n` is zero or `n * floor` exceeds `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(
+    ///     NearGas::from_tgas(30).split_with_floor(2, NearGas::from_tgas(5)),
+    ///     Some(vec![NearGas::from_tgas(15), NearGas::from_tgas(15)])
+    /// );
+    /// assert_eq!(NearGas::from_tgas(30).split_with_floor(4, NearGas::from_tgas(10)), None);
+    /// ```
+    pub fn split_with_floor(self, n: u64, floor: NearGas) -> Option<Vec<NearGas>> {
+        if n == 0 {
+            return None;
+        }
+        let rest = self.checked_sub(floor.checked_mul(n)?)?;
+        let share = floor.as_gas() + rest.as_gas() / n;
+        let remainder = rest.as_gas() % n;
+        Some(
+            (0..n)
+                .map(|index| NearGas::from_gas(share + u64::from(index < remainder)))
+                .collect(),
+        )
+    }
+
+    /// Formats the number of gas with digits grouped by three and separated by commas,
+    /// e.g. `"1,000,000"`. Same as `to_grouped_string_with(',', 3)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_tgas(1).to_grouped_string(), "1,000,000,000,000");
+    /// ```
+    pub fn to_grouped_string(self) -> String {
+        self.to_grouped_string_with(',', 3)
+    }
+
+    /// Formats the number of gas with digits grouped by `group` from the right and separated by `sep`.
+    /// A `group` of zero disables grouping.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_gas(12_345_678).to_grouped_string_with(' ', 3), "12 345 678");
+    /// assert_eq!(NearGas::from_gas(12_345_678).to_grouped_string_with(',', 4), "1234,5678");
+    /// ```
+    pub fn to_grouped_string_with(self, sep: char, group: usize) -> String {
+        let digits = self.as_gas().to_string();
+        if group == 0 {
+            return digits;
+        }
+        let mut grouped =
+            String::with_capacity(digits.len() + digits.len() / group * sep.len_utf8());
+        for (index, digit) in digits.chars().enumerate() {
+            if index != 0 && (digits.len() - index) % group == 0 {
+                grouped.push(sep);
+            }
+            grouped.push(digit);
+        }
+        grouped
+    }
+
+    /// Estimates the gas of a loop as `per_iter * iterations + fixed`,
+    /// returning None if overflow occurred.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(
+    ///     NearGas::for_iterations(NearGas::from_ggas(500), 10, NearGas::from_tgas(2)),
+    ///     Some(NearGas::from_tgas(7))
+    /// );
+    /// assert_eq!(NearGas::for_iterations(NearGas::from_tgas(1), u64::MAX, NearGas::from_gas(0)), None);
+    /// ```
+    pub const fn for_iterations(
+        per_iter: NearGas,
+        iterations: u64,
+        fixed: NearGas,
+    ) -> Option<NearGas> {
+        match per_iter.checked_mul(iterations) {
+            Some(gas) => gas.checked_add(fixed),
+            None => None,
+        }
+    }
+
+    /// Linearly interpolates between `start` and `end` as `start + (end - start) * t`,
+    /// rounded to the nearest gas. `t` is clamped to `0.0..=1.0` (NaN is treated as `0.0`),
+    /// so the result never leaves the range between `start` and `end`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// let (start, end) = (NearGas::from_tgas(10), NearGas::from_tgas(20));
+    /// assert_eq!(NearGas::lerp(start, end, 0.5), NearGas::from_tgas(15));
+    /// assert_eq!(NearGas::lerp(end, start, 0.25), NearGas::from_ggas(17_500));
+    /// ```
+    pub fn lerp(start: NearGas, end: NearGas, t: f64) -> NearGas {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        let difference = end.as_gas() as i128 - start.as_gas() as i128;
+        let value = start.as_gas() as i128 + (difference as f64 * t).round() as i128;
+        let (min, max) = (start.min(end), start.max(end));
+        NearGas::from_gas(value.clamp(min.as_gas() as i128, max.as_gas() as i128) as u64)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{GasComparison, GasUnit, NearGas, NearGasError, MIN_GAS_PRICE_YOCTO, ONE_TERA_GAS};
+
+    #[test]
+    fn checked_add_gas() {
+        let gas = NearGas::from_gas(u64::MAX - 3);
+        let any_gas = NearGas::from_gas(3);
+        let more_gas = NearGas::from_gas(4);
+        assert_eq!(gas.checked_add(any_gas), Some(NearGas::from_gas(u64::MAX)));
+        assert_eq!(gas.checked_add(more_gas), None);
+    }
+
+    #[test]
+    fn checked_sub_gas() {
+        let gas = NearGas::from_gas(3);
+        let any_gas = NearGas::from_gas(1);
+        let more_gas = NearGas::from_gas(4);
+        assert_eq!(gas.checked_sub(any_gas), Some(NearGas::from_gas(2)));
+        assert_eq!(gas.checked_sub(more_gas), None);
+    }
+
+    #[test]
+    fn checked_mul_gas() {
+        let gas = NearGas::from_gas(u64::MAX / 10);
+        assert_eq!(
+            gas.checked_mul(10),
+            Some(NearGas::from_gas(u64::MAX / 10 * 10))
+        );
+        assert_eq!(gas.checked_mul(11), None);
+    }
+
+    #[test]
+    fn checked_div_gas() {
+        let gas = NearGas::from_gas(10);
+        assert_eq!(gas.checked_div(2), Some(NearGas::from_gas(5)));
+        assert_eq!(gas.checked_div(11), Some(NearGas::from_gas(0)));
+        assert_eq!(gas.checked_div(0), None);
+    }
+
+    #[test]
+    fn saturating_add_gas() {
+        let gas = NearGas::from_gas(100);
+        let added_gas = NearGas::from_gas(1);
+        let another_gas = NearGas::from_gas(u64::MAX);
+        assert_eq!(gas.saturating_add(added_gas), NearGas::from_gas(101));
+        assert_eq!(
+            another_gas.saturating_add(added_gas),
+            NearGas::from_gas(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn saturating_sub_gas() {
+        let gas = NearGas::from_gas(100);
+        let rhs_gas = NearGas::from_gas(1);
+        let another_gas = NearGas::from_gas(u64::MIN);
+        assert_eq!(gas.saturating_sub(rhs_gas), NearGas::from_gas(99));
+        assert_eq!(
+            another_gas.saturating_sub(rhs_gas),
+            NearGas::from_gas(u64::MIN)
+        );
+    }
+
+    #[test]
+    fn saturating_mul_gas() {
+        let gas = NearGas::from_gas(2);
+        let rhs = 10;
+        let another_gas = u64::MAX;
+        assert_eq!(gas.saturating_mul(rhs), NearGas::from_gas(20));
+        assert_eq!(gas.saturating_mul(another_gas), NearGas::from_gas(u64::MAX));
+    }
+
+    #[test]
+    fn saturating_div_gas() {
+        let gas = NearGas::from_gas(10);
+        let rhs = 2;
+        let another_gas = 20;
+        assert_eq!(gas.saturating_div(rhs), NearGas::from_gas(5));
+        assert_eq!(gas.saturating_div(another_gas), NearGas::from_gas(0));
+    }
+
+    #[test]
+    fn for_bytes_gas() {
+        let gas_per_byte = NearGas::from_ggas(1);
+        assert_eq!(
+            NearGas::for_bytes(0, gas_per_byte),
+            Some(NearGas::from_gas(0))
+        );
+        assert_eq!(
+            NearGas::for_bytes(100, gas_per_byte),
+            Some(NearGas::from_ggas(100))
+        );
+        assert_eq!(NearGas::for_bytes(u64::MAX, gas_per_byte), None);
+    }
+
+    #[test]
+    fn ord_by_gas_value() {
+        let mut gas = vec![
+            NearGas::from_tgas(1),
+            NearGas::from_gas(1),
+            NearGas::from_ggas(1),
+            NearGas::from_gas(0),
+        ];
+        gas.sort();
+        assert_eq!(
+            gas,
+            vec![
+                NearGas::from_gas(0),
+                NearGas::from_gas(1),
+                NearGas::from_ggas(1),
+                NearGas::from_tgas(1),
+            ]
+        );
+        gas.sort_by_key(|gas| gas.min_heap_key());
+        assert_eq!(gas.first(), Some(&NearGas::from_tgas(1)));
+        assert_eq!(gas.last(), Some(&NearGas::from_gas(0)));
+    }
+
+    #[test]
+    fn ensure_within_transaction_limit_gas() {
+        for gas in [
+            NearGas::from_gas(0),
+            NearGas::from_tgas(299),
+            NearGas::from_tgas(300),
+        ] {
+            assert_eq!(gas.ensure_within_transaction_limit(), Ok(gas));
+        }
+        let gas = NearGas::from_gas(300_000_000_000_001);
+        assert_eq!(
+            gas.ensure_within_transaction_limit(),
+            Err(NearGasError::ExceedsTransactionLimit(gas))
+        );
+    }
+
+    #[test]
+    fn basis_points_of_gas() {
+        let baseline = NearGas::from_tgas(10);
+        assert_eq!(baseline.basis_points_of(baseline), Some(10_000));
+        assert_eq!(NearGas::from_tgas(5).basis_points_of(baseline), Some(5_000));
+        assert_eq!(NearGas::from_ggas(15).basis_points_of(baseline), Some(15));
+        assert_eq!(
+            NearGas::from_tgas(25).basis_points_of(baseline),
+            Some(25_000)
+        );
+        assert_eq!(NearGas::from_gas(0).basis_points_of(baseline), Some(0));
+        assert_eq!(baseline.basis_points_of(NearGas::from_gas(0)), None);
+        assert_eq!(
+            NearGas::from_gas(u64::MAX).basis_points_of(NearGas::from_gas(1)),
+            None
+        );
+    }
+
+    #[test]
+    fn merge_sum_gas() {
+        let a = NearGas::from_tgas(1);
+        let b = NearGas::from_ggas(500);
+        assert_eq!(
+            NearGas::merge_sum(Some(a), Some(b)),
+            Some(NearGas::from_ggas(1500))
+        );
+        assert_eq!(NearGas::merge_sum(Some(a), None), Some(a));
+        assert_eq!(NearGas::merge_sum(None, Some(b)), Some(b));
+        assert_eq!(NearGas::merge_sum(None, None), None);
+        assert_eq!(
+            NearGas::merge_sum(Some(NearGas::from_gas(u64::MAX)), Some(a)),
+            None
+        );
+    }
+
+    #[test]
+    fn to_ggas_string_gas() {
+        for (gas, expected) in [
+            (NearGas::from_gas(0), "0 Ggas"),
+            (NearGas::from_gas(1), "0.000000001 Ggas"),
+            (NearGas::from_gas(500_000_000), "0.5 Ggas"),
+            (NearGas::from_ggas(1), "1 Ggas"),
+            (NearGas::from_tgas(300), "300000 Ggas"),
+            (NearGas::from_gas(1_250_000_000), "1.25 Ggas"),
+            (NearGas::from_gas(u64::MAX), "18446744073.709551615 Ggas"),
+        ] {
+            assert_eq!(gas.to_ggas_string(), expected);
+        }
+    }
+
+    #[test]
+    fn checked_sub_verbose_gas() {
+        let gas = NearGas::from_tgas(3);
+        assert_eq!(
+            gas.checked_sub_verbose(NearGas::from_tgas(1)),
+            Ok(NearGas::from_tgas(2))
+        );
+        assert_eq!(gas.checked_sub_verbose(gas), Ok(NearGas::from_gas(0)));
+        assert_eq!(
+            gas.checked_sub_verbose(NearGas::from_tgas(5)),
+            Err(NearGas::from_tgas(2))
+        );
+        assert_eq!(
+            NearGas::from_gas(0).checked_sub_verbose(NearGas::from_gas(u64::MAX)),
+            Err(NearGas::from_gas(u64::MAX))
+        );
+    }
+
+    #[test]
+    fn ratio_gas() {
+        assert_eq!(
+            NearGas::from_gas(6).ratio(NearGas::from_gas(4)),
+            Some((3, 2))
+        );
+        assert_eq!(
+            NearGas::from_ggas(1).ratio(NearGas::from_tgas(1)),
+            Some((1, 1000))
+        );
+        assert_eq!(
+            NearGas::from_gas(7).ratio(NearGas::from_gas(7)),
+            Some((1, 1))
+        );
+        assert_eq!(
+            NearGas::from_gas(0).ratio(NearGas::from_gas(5)),
+            Some((0, 1))
+        );
+        assert_eq!(NearGas::from_gas(6).ratio(NearGas::from_gas(0)), None);
+    }
+
+    #[test]
+    fn log10_floor_gas() {
+        for exp in 0..=19 {
+            let gas = NearGas::from_gas(10u64.pow(exp));
+            assert_eq!(gas.log10_floor(), Some(exp));
+        }
+        assert_eq!(NearGas::from_gas(999).log10_floor(), Some(2));
+        assert_eq!(NearGas::from_gas(u64::MAX).log10_floor(), Some(19));
+        assert_eq!(NearGas::from_gas(0).log10_floor(), None);
+    }
+
+    #[test]
+    fn from_cost_gas() {
+        let gas_price = 100_000_000;
+        assert_eq!(
+            NearGas::from_cost(300 * 10u128.pow(12) * gas_price, gas_price),
+            Some(NearGas::from_tgas(300))
+        );
+        assert_eq!(
+            NearGas::from_cost(u64::MAX as u128 * 3, 3),
+            Some(NearGas::from_gas(u64::MAX))
+        );
+        assert_eq!(NearGas::from_cost(u64::MAX as u128 * 3 + 3, 3), None);
+        assert_eq!(NearGas::from_cost(0, gas_price), Some(NearGas::from_gas(0)));
+        assert_eq!(NearGas::from_cost(gas_price, 0), None);
+    }
+
+    #[test]
+    fn refund_gas() {
+        let used = NearGas::from_tgas(100);
+        assert_eq!(
+            NearGas::refund(used, NearGas::from_tgas(25)),
+            NearGas::from_tgas(75)
+        );
+        assert_eq!(NearGas::refund(used, used), NearGas::from_gas(0));
+        assert_eq!(
+            NearGas::refund(used, NearGas::from_tgas(101)),
+            NearGas::from_gas(0)
+        );
+    }
+
+    #[test]
+    fn efficiency_percent_gas() {
+        let used = NearGas::from_tgas(100);
+        assert_eq!(
+            NearGas::efficiency_percent(used, NearGas::from_tgas(25)),
+            Some(25.0)
+        );
+        assert_eq!(NearGas::efficiency_percent(used, used), Some(100.0));
+        assert_eq!(
+            NearGas::efficiency_percent(used, NearGas::from_tgas(150)),
+            Some(150.0)
+        );
+        assert_eq!(
+            NearGas::efficiency_percent(NearGas::from_gas(0), used),
+            None
+        );
+    }
+
+    #[test]
+    fn consume_gas() {
+        let gas = NearGas::from_tgas(300);
+        assert_eq!(
+            gas.consume(NearGas::from_tgas(75)),
+            Some((NearGas::from_tgas(225), 0.25))
+        );
+        assert_eq!(gas.consume(gas), Some((NearGas::from_gas(0), 1.0)));
+        assert_eq!(gas.consume(NearGas::from_gas(0)), Some((gas, 0.0)));
+        assert_eq!(
+            NearGas::from_gas(0).consume(NearGas::from_gas(0)),
+            Some((NearGas::from_gas(0), 0.0))
+        );
+        assert_eq!(gas.consume(NearGas::from_gas(300_000_000_000_001)), None);
+    }
+
+    #[test]
+    fn min_max_of_gas() {
+        assert_eq!(NearGas::min_of(&[]), None);
+        assert_eq!(NearGas::max_of(&[]), None);
+
+        let single = [NearGas::from_tgas(5)];
+        assert_eq!(NearGas::min_of(&single), Some(NearGas::from_tgas(5)));
+        assert_eq!(NearGas::max_of(&single), Some(NearGas::from_tgas(5)));
+
+        let samples = [
+            NearGas::from_tgas(5),
+            NearGas::from_gas(7),
+            NearGas::from_tgas(300),
+            NearGas::from_ggas(1),
+        ];
+        assert_eq!(NearGas::min_of(&samples), Some(NearGas::from_gas(7)));
+        assert_eq!(NearGas::max_of(&samples), Some(NearGas::from_tgas(300)));
+    }
+
+    #[test]
+    fn checked_add_tgas_gas() {
+        let zero = NearGas::from_gas(0);
+        let max_tgas = u64::MAX / 10u64.pow(12);
+        assert_eq!(
+            zero.checked_add_tgas(max_tgas),
+            Some(NearGas::from_tgas(max_tgas))
+        );
+        assert_eq!(zero.checked_add_tgas(max_tgas + 1), None);
+        assert_eq!(NearGas::from_gas(u64::MAX).checked_add_tgas(1), None);
+        assert_eq!(
+            NearGas::from_gas(u64::MAX).checked_add_tgas(0),
+            Some(NearGas::from_gas(u64::MAX))
+        );
+    }
+
+    #[test]
+    fn checked_add_ggas_gas() {
+        let zero = NearGas::from_gas(0);
+        let max_ggas = u64::MAX / 10u64.pow(9);
+        assert_eq!(
+            zero.checked_add_ggas(max_ggas),
+            Some(NearGas::from_ggas(max_ggas))
+        );
+        assert_eq!(zero.checked_add_ggas(max_ggas + 1), None);
+        assert_eq!(NearGas::from_gas(u64::MAX).checked_add_ggas(1), None);
+    }
+
+    #[test]
+    fn to_tgas_fixed_gas() {
+        for (gas, decimals, expected) in [
+            (NearGas::from_gas(0), 6, "0.000000"),
+            (NearGas::from_gas(0), 0, "0"),
+            (NearGas::from_ggas(1500), 6, "1.500000"),
+            (NearGas::from_ggas(1500), 0, "2"),
+            (NearGas::from_ggas(1499), 0, "1"),
+            (NearGas::from_gas(1_234_567_500_000), 6, "1.234568"),
+            (NearGas::from_gas(1_234_567_499_999), 6, "1.234567"),
+            (NearGas::from_gas(999_999_500_000), 6, "1.000000"),
+            (NearGas::from_gas(1), 12, "0.000000000001"),
+            (NearGas::from_gas(1), 14, "0.00000000000100"),
+            (NearGas::from_gas(u64::MAX), 0, "18446744"),
+            (NearGas::from_gas(u64::MAX), 3, "18446744.074"),
+        ] {
+            assert_eq!(gas.to_tgas_fixed(decimals), expected);
+        }
+    }
+
+    #[test]
+    fn weighted_merge_gas() {
+        let a = NearGas::from_tgas(10);
+        let b = NearGas::from_tgas(20);
+        assert_eq!(a.weighted_merge(1, b, 1), Some(NearGas::from_tgas(15)));
+        assert_eq!(a.weighted_merge(3, b, 1), Some(NearGas::from_ggas(12_500)));
+        assert_eq!(a.weighted_merge(1, b, 0), Some(a));
+        assert_eq!(a.weighted_merge(0, b, 7), Some(b));
+        assert_eq!(a.weighted_merge(0, b, 0), None);
+        let max = NearGas::from_gas(u64::MAX);
+        assert_eq!(max.weighted_merge(u64::MAX, max, 1), Some(max));
+        assert_eq!(max.weighted_merge(u64::MAX, max, u64::MAX), None);
+    }
+
+    #[test]
+    fn saturating_add_signed_gas() {
+        let gas = NearGas::from_tgas(1);
+        assert_eq!(
+            gas.saturating_add_signed(1_000_000_000),
+            NearGas::from_ggas(1001)
+        );
+        assert_eq!(
+            gas.saturating_add_signed(-1_000_000_000),
+            NearGas::from_ggas(999)
+        );
+        assert_eq!(gas.saturating_add_signed(i64::MIN), NearGas::from_gas(0));
+        assert_eq!(
+            NearGas::from_gas(u64::MAX - 1).saturating_add_signed(i64::MAX),
+            NearGas::from_gas(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn to_cli_arg_gas() {
+        for (gas, expected) in [
+            (NearGas::from_gas(0), "0 TGas"),
+            (NearGas::from_tgas(30), "30 TGas"),
+            (NearGas::from_tgas(300), "300 TGas"),
+            (NearGas::from_ggas(100), "0.1 TGas"),
+            (NearGas::from_gas(1), "0.000000000001 TGas"),
+        ] {
+            assert_eq!(gas.to_cli_arg(), expected);
+            assert_eq!(expected.parse::<NearGas>(), Ok(gas));
+        }
+    }
+
+    #[test]
+    fn mul_div_gas() {
+        let gas = NearGas::from_tgas(300);
+        assert_eq!(gas.mul_div(3, 4), Some(NearGas::from_tgas(225)));
+        assert_eq!(gas.mul_div(1, 3), Some(NearGas::from_tgas(100)));
+        assert_eq!(gas.mul_div(u64::MAX, u64::MAX), Some(gas));
+        assert_eq!(
+            NearGas::from_gas(u64::MAX / 2).mul_div(u64::MAX, u64::MAX / 2),
+            Some(NearGas::from_gas(u64::MAX))
+        );
+        assert_eq!(gas.mul_div(u64::MAX, 1), None);
+        assert_eq!(gas.mul_div(1, 0), None);
+    }
+
+    #[test]
+    fn compare_labeled_gas() {
+        let gas = NearGas::from_tgas(10);
+        let tolerance = NearGas::from_ggas(500);
+        for (other, expected) in [
+            (NearGas::from_ggas(10_501), GasComparison::Less),
+            (NearGas::from_ggas(10_500), GasComparison::Similar),
+            (gas, GasComparison::Similar),
+            (NearGas::from_ggas(9_500), GasComparison::Similar),
+            (NearGas::from_ggas(9_499), GasComparison::Greater),
+        ] {
+            assert_eq!(gas.compare_labeled(other, tolerance), expected);
+        }
+        assert_eq!(
+            gas.compare_labeled(NearGas::from_gas(10_000_000_000_001), NearGas::from_gas(0)),
+            GasComparison::Less
+        );
+        assert_eq!(GasComparison::Similar.to_string(), "about the same");
+    }
+
+    #[test]
+    fn cumulative_gas() {
+        assert_eq!(NearGas::cumulative(&[]), Some(vec![]));
+        assert_eq!(
+            NearGas::cumulative(&[NearGas::from_gas(5)]),
+            Some(vec![NearGas::from_gas(5)])
+        );
+        assert_eq!(
+            NearGas::cumulative(&[
+                NearGas::from_ggas(500),
+                NearGas::from_gas(0),
+                NearGas::from_tgas(1)
+            ]),
+            Some(vec![
+                NearGas::from_ggas(500),
+                NearGas::from_ggas(500),
+                NearGas::from_ggas(1500)
+            ])
+        );
+        assert_eq!(
+            NearGas::cumulative(&[NearGas::from_gas(u64::MAX), NearGas::from_gas(1)]),
+            None
+        );
+    }
+
+    #[test]
+    fn checked_sum_indexed_gas() {
+        assert_eq!(NearGas::checked_sum_indexed(&[]), Ok(NearGas::from_gas(0)));
+        assert_eq!(
+            NearGas::checked_sum_indexed(&[
+                NearGas::from_tgas(100),
+                NearGas::from_ggas(500),
+                NearGas::from_gas(1)
+            ]),
+            Ok(NearGas::from_gas(100_500_000_000_001))
+        );
+        assert_eq!(
+            NearGas::checked_sum_indexed(&[
+                NearGas::from_gas(u64::MAX - 1),
+                NearGas::from_gas(1),
+                NearGas::from_gas(0),
+                NearGas::from_gas(1),
+                NearGas::from_gas(1),
+            ]),
+            Err(3)
+        );
+    }
+
+    #[test]
+    fn min_cost_near_gas() {
+        let one_near = 10u128.pow(24);
+        assert_eq!(NearGas::from_gas(1).min_cost_near(), 100_000_000);
+        assert_eq!(NearGas::from_tgas(1).min_cost_near(), one_near / 10_000);
+        assert_eq!(NearGas::from_tgas(300).min_cost_near(), one_near * 3 / 100);
+        assert_eq!(
+            NearGas::from_gas(u64::MAX).min_cost_near(),
+            u64::MAX as u128 * 100_000_000
+        );
+    }
+
+    #[test]
+    fn cost_at_min_price_gas() {
+        for gas in [
+            NearGas::from_gas(0),
+            NearGas::from_gas(1),
+            NearGas::from_tgas(300),
+            NearGas::from_gas(u64::MAX),
+        ] {
+            assert_eq!(
+                gas.cost_at_min_price(),
+                gas.as_gas() as u128 * MIN_GAS_PRICE_YOCTO
+            );
+            assert_eq!(gas.cost_at_min_price(), gas.min_cost_near());
+            assert_eq!(
+                NearGas::from_cost(gas.cost_at_min_price(), MIN_GAS_PRICE_YOCTO),
+                Some(gas)
+            );
+        }
+    }
+
+    #[test]
+    fn as_multiple_of_gas() {
+        let base = NearGas::from_tgas(2);
+        assert_eq!(NearGas::from_tgas(6).as_multiple_of(base), Some(3.0));
+        assert_eq!(NearGas::from_tgas(7).as_multiple_of(base), Some(3.5));
+        assert_eq!(NearGas::from_tgas(1).as_multiple_of(base), Some(0.5));
+        assert_eq!(NearGas::from_gas(0).as_multiple_of(base), Some(0.0));
+        assert_eq!(base.as_multiple_of(NearGas::from_gas(0)), None);
+    }
+
+    #[test]
+    fn approx_duration_gas() {
+        use std::time::Duration;
+
+        assert_eq!(
+            NearGas::from_tgas(1).approx_duration(),
+            Duration::from_millis(1)
+        );
+        assert_eq!(
+            NearGas::from_ggas(1).approx_duration(),
+            Duration::from_micros(1)
+        );
+        assert_eq!(NearGas::from_gas(999_999).approx_duration(), Duration::ZERO);
+        assert_eq!(
+            NearGas::from_gas(u64::MAX).approx_duration(),
+            Duration::from_nanos(u64::MAX / 1_000_000)
+        );
+    }
+
+    #[test]
+    fn from_approx_duration_gas() {
+        use std::time::Duration;
+
+        assert_eq!(
+            NearGas::from_approx_duration(Duration::from_millis(1)),
+            NearGas::from_tgas(1)
+        );
+        assert_eq!(
+            NearGas::from_approx_duration(NearGas::from_tgas(1).approx_duration()),
+            NearGas::from_tgas(1)
+        );
+        assert_eq!(
+            NearGas::from_approx_duration(Duration::from_nanos(1)),
+            NearGas::from_gas(1_000_000)
+        );
+        assert_eq!(
+            NearGas::from_approx_duration(Duration::ZERO),
+            NearGas::from_gas(0)
+        );
+        assert_eq!(
+            NearGas::from_approx_duration(Duration::from_secs(u64::MAX)),
+            NearGas::MAX
+        );
+        assert_eq!(NearGas::from_approx_duration(Duration::MAX), NearGas::MAX);
+    }
+
+    #[test]
+    fn saturating_mul_ratio_gas() {
+        let gas = NearGas::from_tgas(300);
+        assert_eq!(gas.saturating_mul_ratio(1, 3), NearGas::from_tgas(100));
+        assert_eq!(gas.saturating_mul_ratio(0, 3), NearGas::from_gas(0));
+        assert_eq!(
+            NearGas::MAX.saturating_mul_ratio(u64::MAX, u64::MAX),
+            NearGas::MAX
+        );
+        assert_eq!(gas.saturating_mul_ratio(u64::MAX, 1), NearGas::MAX);
+        assert_eq!(gas.saturating_mul_ratio(1, 0), NearGas::MAX);
+        assert_eq!(
+            NearGas::from_gas(0).saturating_mul_ratio(1, 0),
+            NearGas::MAX
+        );
+    }
+
+    #[test]
+    fn to_si_string_gas() {
+        for (gas, expected) in [
+            (NearGas::from_gas(0), "0"),
+            (NearGas::from_gas(999), "999"),
+            (NearGas::from_gas(1_000), "1K"),
+            (NearGas::from_gas(1_549), "1.5K"),
+            (NearGas::from_gas(1_550), "1.6K"),
+            (NearGas::from_gas(999_949), "999.9K"),
+            (NearGas::from_gas(999_950), "1M"),
+            (NearGas::from_gas(2_500_000), "2.5M"),
+            (NearGas::from_ggas(1), "1G"),
+            (NearGas::from_ggas(300), "300G"),
+            (NearGas::from_ggas(1500), "1.5T"),
+            (NearGas::from_tgas(300), "300T"),
+            (NearGas::from_tgas(5000), "5000T"),
+            (NearGas::MAX, "18446744.1T"),
+        ] {
+            assert_eq!(gas.to_si_string(), expected, "gas: {}", gas.as_gas());
+        }
+    }
+
+    #[test]
+    fn natural_unit_gas() {
+        for (gas, expected) in [
+            (NearGas::from_gas(0), GasUnit::Tgas),
+            (NearGas::from_tgas(1), GasUnit::Tgas),
+            (NearGas::from_tgas(300), GasUnit::Tgas),
+            (NearGas::from_ggas(1), GasUnit::Ggas),
+            (NearGas::from_ggas(1001), GasUnit::Ggas),
+            (NearGas::from_gas(1), GasUnit::Gas),
+            (NearGas::from_gas(1_000_000_000_001), GasUnit::Gas),
+            (NearGas::MAX, GasUnit::Gas),
+        ] {
+            assert_eq!(gas.natural_unit(), expected, "gas: {}", gas.as_gas());
+            assert_eq!(gas.as_gas() % expected.as_gas(), 0);
+        }
+    }
+
+    #[test]
+    fn varint_gas() {
+        for (val, expected_len) in [
+            (0, 1),
+            (1, 1),
+            (127, 1),
+            (128, 2),
+            (16_383, 2),
+            (16_384, 3),
+            (1_000_000_000, 5),
+            (300_000_000_000_000, 7),
+            (u64::MAX, 10),
+        ] {
+            let gas = NearGas::from_gas(val);
+            let bytes = gas.to_varint();
+            assert_eq!(bytes.len(), expected_len, "gas: {}", val);
+            assert_eq!(NearGas::from_varint(&bytes), Ok((gas, expected_len)));
+        }
+    }
+
+    #[test]
+    fn from_varint_invalid() {
+        assert_eq!(
+            NearGas::from_varint(&[]),
+            Err(NearGasError::InvalidVarint(vec![]))
+        );
+        assert_eq!(
+            NearGas::from_varint(&[0x80, 0x80]),
+            Err(NearGasError::InvalidVarint(vec![0x80, 0x80]))
+        );
+        // u64::MAX + 1
+        let too_large = [0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x02];
+        assert_eq!(
+            NearGas::from_varint(&too_large),
+            Err(NearGasError::InvalidVarint(too_large.to_vec()))
+        );
+        let too_long = [0xff; 11];
+        assert_eq!(
+            NearGas::from_varint(&too_long),
+            Err(NearGasError::InvalidVarint(vec![0xff; 10]))
+        );
+    }
+
+    #[test]
+    fn round_sig_figs_gas() {
+        let gas = NearGas::from_gas(3_456_789_000_000);
+        assert_eq!(gas.round_sig_figs(2), NearGas::from_gas(3_500_000_000_000));
+        assert_eq!(gas.round_sig_figs(3), NearGas::from_gas(3_460_000_000_000));
+        assert_eq!(
+            NearGas::from_gas(3_450_000_000_000).round_sig_figs(2),
+            NearGas::from_gas(3_500_000_000_000)
+        );
+        assert_eq!(
+            NearGas::from_gas(9_960).round_sig_figs(2),
+            NearGas::from_gas(10_000)
+        );
+        assert_eq!(gas.round_sig_figs(20), gas);
+        assert_eq!(gas.round_sig_figs(0), NearGas::from_gas(0));
+        assert_eq!(NearGas::from_gas(0).round_sig_figs(2), NearGas::from_gas(0));
+        assert_eq!(NearGas::MAX.round_sig_figs(1), NearGas::MAX);
+    }
+
+    #[test]
+    fn savings_percent_gas() {
+        let old = NearGas::from_tgas(100);
+        assert_eq!(
+            NearGas::savings_percent(old, NearGas::from_tgas(75)),
+            Some(25.0)
+        );
+        assert_eq!(NearGas::savings_percent(old, old), Some(0.0));
+        assert_eq!(
+            NearGas::savings_percent(old, NearGas::from_tgas(150)),
+            Some(-50.0)
+        );
+        assert_eq!(NearGas::savings_percent(NearGas::from_gas(0), old), None);
+    }
+
+    #[test]
+    fn checked_refund_gas() {
+        let prepaid = NearGas::from_tgas(100);
+        assert_eq!(
+            NearGas::checked_refund(prepaid, NearGas::from_tgas(25)),
+            Some(NearGas::from_tgas(75))
+        );
+        assert_eq!(
+            NearGas::checked_refund(prepaid, prepaid),
+            Some(NearGas::from_gas(0))
+        );
+        assert_eq!(
+            NearGas::checked_refund(prepaid, NearGas::from_tgas(101)),
+            None
+        );
+    }
+
+    #[test]
+    fn to_tgas_f64_checked_gas() {
+        assert_eq!(
+            NearGas::from_tgas(300).to_tgas_f64_checked(),
+            (300.0, false)
+        );
+        assert!(!NearGas::from_gas((1 << 53) - 1).to_tgas_f64_checked().1);
+        assert!(!NearGas::from_gas(1 << 53).to_tgas_f64_checked().1);
+        assert!(NearGas::from_gas((1 << 53) + 1).to_tgas_f64_checked().1);
+        let (tgas, lossy) = NearGas::from_gas(u64::MAX).to_tgas_f64_checked();
+        assert!(lossy);
+        assert_eq!(tgas, u64::MAX as f64 / 1e12);
+    }
+
+    #[test]
+    fn const_comparators_gas() {
+        const SMALL: NearGas = NearGas::from_tgas(5);
+        const LARGE: NearGas = NearGas::from_tgas(300);
+        const _: () = assert!(SMALL.le_const(LARGE));
+        const _: () = assert!(SMALL.le_const(SMALL));
+        const _: () = assert!(SMALL.lt_const(LARGE));
+        const _: () = assert!(!LARGE.lt_const(SMALL));
+        const _: () = assert!(!SMALL.lt_const(SMALL));
+        const _: () = assert!(SMALL.eq_const(NearGas::from_ggas(5000)));
+        const _: () = assert!(!SMALL.eq_const(LARGE));
+        for (a, b) in [(SMALL, LARGE), (LARGE, SMALL), (SMALL, SMALL)] {
+            assert_eq!(a.eq_const(b), a == b);
+            assert_eq!(a.lt_const(b), a < b);
+            assert_eq!(a.le_const(b), a <= b);
+        }
+    }
+
+    #[test]
+    fn forward_after_reserve_gas() {
+        let reserve = NearGas::DEFAULT_CALLBACK_RESERVE;
+        assert_eq!(reserve, NearGas::from_tgas(5));
+        assert_eq!(
+            NearGas::forward_after_reserve(NearGas::from_tgas(100), reserve),
+            NearGas::from_tgas(95)
+        );
+        assert_eq!(
+            NearGas::forward_after_reserve(reserve, reserve),
+            NearGas::from_gas(0)
+        );
+        assert_eq!(
+            NearGas::forward_after_reserve(NearGas::from_tgas(3), reserve),
+            NearGas::from_gas(0)
+        );
+    }
+
+    #[test]
+    fn cmp_tgas_gas() {
+        use std::cmp::Ordering;
+
+        assert_eq!(
+            NearGas::from_tgas(3).cmp_tgas(NearGas::from_gas(3_999_999_999_999)),
+            Ordering::Equal
+        );
+        assert_eq!(
+            NearGas::from_gas(999_999_999_999).cmp_tgas(NearGas::from_gas(0)),
+            Ordering::Equal
+        );
+        assert_eq!(
+            NearGas::from_tgas(4).cmp_tgas(NearGas::from_gas(3_999_999_999_999)),
+            Ordering::Greater
+        );
+
+        let mut gases = [
+            NearGas::from_ggas(2_500),
+            NearGas::from_ggas(1_900),
+            NearGas::from_ggas(2_100),
+            NearGas::from_ggas(1_100),
+        ];
+        gases.sort_by(|a, b| a.cmp_tgas(*b));
+        assert_eq!(
+            gases,
+            [
+                NearGas::from_ggas(1_900),
+                NearGas::from_ggas(1_100),
+                NearGas::from_ggas(2_500),
+                NearGas::from_ggas(2_100),
+            ]
+        );
+    }
+
+    #[test]
+    fn percent_of_transaction_limit_gas() {
+        assert_eq!(NearGas::from_gas(0).percent_of_transaction_limit(), 0.0);
+        assert_eq!(NearGas::from_tgas(150).percent_of_transaction_limit(), 50.0);
+        assert_eq!(
+            NearGas::from_tgas(300).percent_of_transaction_limit(),
+            100.0
+        );
+        assert_eq!(
+            NearGas::from_tgas(450).percent_of_transaction_limit(),
+            150.0
+        );
+    }
+
+    #[test]
+    fn geometric_mean_gas() {
+        fn assert_close(actual: NearGas, expected: f64) {
+            let diff = (actual.as_gas() as f64 - expected).abs();
+            assert!(
+                diff <= expected * 1e-12,
+                "{} vs {}",
+                actual.as_gas(),
+                expected
+            );
+        }
+
+        // (2 * 8 * 32) ^ (1/3) = 8
+        assert_close(
+            NearGas::geometric_mean(&[
+                NearGas::from_tgas(2),
+                NearGas::from_tgas(8),
+                NearGas::from_tgas(32),
+            ])
+            .unwrap(),
+            8e12,
+        );
+        // sqrt(10 * 1000) = 100
+        assert_close(
+            NearGas::geometric_mean(&[NearGas::from_gas(10), NearGas::from_gas(1000)]).unwrap(),
+            100.0,
+        );
+        assert_eq!(
+            NearGas::geometric_mean(&[NearGas::from_gas(42)]),
+            Some(NearGas::from_gas(42))
+        );
+        assert_close(
+            NearGas::geometric_mean(&[NearGas::MAX, NearGas::MAX]).unwrap(),
+            u64::MAX as f64,
+        );
+        assert_eq!(NearGas::geometric_mean(&[]), None);
+        assert_eq!(
+            NearGas::geometric_mean(&[NearGas::from_tgas(1), NearGas::from_gas(0)]),
+            None
+        );
+    }
+
+    #[test]
+    fn diff_gas() {
+        let baseline = NearGas::from_tgas(100);
+        assert_eq!(
+            NearGas::from_tgas(150).diff(baseline),
+            (50_000_000_000_000, Some(50.0))
+        );
+        assert_eq!(
+            NearGas::from_tgas(75).diff(baseline),
+            (-25_000_000_000_000, Some(-25.0))
+        );
+        assert_eq!(baseline.diff(baseline), (0, Some(0.0)));
+        assert_eq!(
+            NearGas::from_tgas(1).diff(NearGas::from_gas(0)),
+            (1_000_000_000_000, None)
+        );
+        assert_eq!(
+            NearGas::from_gas(0).diff(NearGas::MAX),
+            (-(u64::MAX as i128), Some(-100.0))
+        );
+    }
+
+    #[test]
+    fn require_whole_tgas_gas() {
+        assert_eq!(NearGas::from_gas(0).require_whole_tgas(), Ok(0));
+        assert_eq!(NearGas::from_tgas(300).require_whole_tgas(), Ok(300));
+        assert_eq!(NearGas::from_ggas(5_000).require_whole_tgas(), Ok(5));
+        for gas in [
+            NearGas::from_gas(1),
+            NearGas::from_ggas(1_500),
+            NearGas::from_gas(ONE_TERA_GAS - 1),
+            NearGas::MAX,
+        ] {
+            assert_eq!(
+                gas.require_whole_tgas(),
+                Err(NearGasError::NotWholeTgas(gas))
+            );
+        }
+    }
+
+    #[test]
+    fn backoff_schedule_gas() {
+        assert_eq!(
+            NearGas::backoff_schedule(NearGas::from_tgas(1), 3, 4),
+            vec![
+                NearGas::from_tgas(1),
+                NearGas::from_tgas(3),
+                NearGas::from_tgas(9),
+                NearGas::from_tgas(27),
+            ]
+        );
+        assert_eq!(
+            NearGas::backoff_schedule(NearGas::from_tgas(1), 1_000_000, 4),
+            vec![
+                NearGas::from_tgas(1),
+                NearGas::from_tgas(1_000_000),
+                NearGas::MAX,
+                NearGas::MAX,
+            ]
+        );
+        assert_eq!(
+            NearGas::backoff_schedule(NearGas::from_tgas(1), 2, 0),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn within_percent_gas() {
+        let gas = NearGas::from_tgas(100);
+        assert!(gas.within_percent(gas, 0.0));
+        assert!(gas.within_percent(NearGas::from_tgas(95), 5.0));
+        assert!(NearGas::from_tgas(95).within_percent(gas, 5.0));
+        assert!(gas.within_percent(NearGas::from_ggas(104_999), 5.0));
+        assert!(!gas.within_percent(NearGas::from_ggas(94_999), 5.0));
+        assert!(!gas.within_percent(NearGas::from_tgas(106), 5.0));
+        assert!(NearGas::from_gas(100).within_percent(NearGas::from_gas(105), 5.0));
+        assert!(NearGas::from_gas(0).within_percent(NearGas::from_gas(0), 0.0));
+        assert!(!NearGas::from_gas(0).within_percent(NearGas::from_gas(1), 99.0));
+    }
+
+    #[test]
+    fn string_in_unit_gas() {
+        for (gas, unit, expected) in [
+            (NearGas::from_ggas(1500), GasUnit::Tgas, "1.5"),
+            (NearGas::from_ggas(1500), GasUnit::Ggas, "1500"),
+            (NearGas::from_gas(1), GasUnit::Tgas, "0.000000000001"),
+            (
+                NearGas::from_gas(1_500_000_001),
+                GasUnit::Ggas,
+                "1.500000001",
+            ),
+            (NearGas::from_tgas(3), GasUnit::Gas, "3000000000000"),
+            (NearGas::MAX, GasUnit::Tgas, "18446744.073709551615"),
+        ] {
+            let s = gas.to_string_in(unit);
+            assert_eq!(s, expected);
+            assert_eq!(NearGas::from_string_in(&s, unit), Ok(gas));
+        }
+        assert_eq!(
+            NearGas::from_string_in("1.5", GasUnit::Gas),
+            Err(NearGasError::IncorrectNumber(
+                crate::DecimalNumberParsingError::LongFractional("5".to_owned())
+            ))
+        );
+    }
+
+    #[test]
+    fn percentile_gas() {
+        let mut values = [
+            NearGas::from_tgas(40),
+            NearGas::from_tgas(10),
+            NearGas::from_tgas(30),
+            NearGas::from_tgas(20),
+        ];
+        assert_eq!(
+            NearGas::percentile(&mut values, 50.0),
+            Some(NearGas::from_tgas(25))
+        );
+        assert_eq!(
+            values,
+            [
+                NearGas::from_tgas(10),
+                NearGas::from_tgas(20),
+                NearGas::from_tgas(30),
+                NearGas::from_tgas(40),
+            ]
+        );
+        assert_eq!(
+            NearGas::percentile(&mut values, 0.0),
+            Some(NearGas::from_tgas(10))
+        );
+        assert_eq!(
+            NearGas::percentile(&mut values, 100.0),
+            Some(NearGas::from_tgas(40))
+        );
+        assert_eq!(
+            NearGas::percentile(&mut [NearGas::from_gas(0), NearGas::MAX], 100.0),
+            Some(NearGas::MAX)
+        );
+        assert_eq!(
+            NearGas::percentile(&mut [NearGas::from_tgas(7)], 95.0),
+            Some(NearGas::from_tgas(7))
+        );
+        assert_eq!(NearGas::percentile(&mut [], 50.0), None);
+        assert_eq!(NearGas::percentile(&mut values, 100.1), None);
+        assert_eq!(NearGas::percentile(&mut values, -1.0), None);
+        assert_eq!(NearGas::percentile(&mut values, f64::NAN), None);
+    }
+
+    #[test]
+    fn split_with_floor_gas() {
+        let floor = NearGas::from_tgas(5);
+        assert_eq!(
+            NearGas::from_tgas(30).split_with_floor(3, floor),
+            Some(vec![NearGas::from_tgas(10); 3])
+        );
+        assert_eq!(
+            NearGas::from_tgas(15).split_with_floor(3, floor),
+            Some(vec![floor; 3])
+        );
+        assert_eq!(
+            NearGas::from_gas(11).split_with_floor(3, NearGas::from_gas(2)),
+            Some(vec![
+                NearGas::from_gas(4),
+                NearGas::from_gas(4),
+                NearGas::from_gas(3)
+            ])
+        );
+        assert_eq!(NearGas::from_tgas(30).split_with_floor(0, floor), None);
+        assert_eq!(
+            NearGas::from_gas(0).split_with_floor(0, NearGas::from_gas(0)),
+            None
+        );
+        assert_eq!(NearGas::from_tgas(14).split_with_floor(3, floor), None);
+        assert_eq!(NearGas::MAX.split_with_floor(u64::MAX, floor), None);
+    }
+
+    #[test]
+    fn to_grouped_string_gas() {
+        for (gas, expected) in [
+            (0, "0"),
+            (999, "999"),
+            (1_000, "1,000"),
+            (123_456, "123,456"),
+            (1_234_567, "1,234,567"),
+            (u64::MAX, "18,446,744,073,709,551,615"),
+        ] {
+            assert_eq!(NearGas::from_gas(gas).to_grouped_string(), expected);
+            assert_eq!(
+                NearGas::from_gas(gas).to_grouped_string_with(',', 3),
+                expected
+            );
+        }
+        for (gas, expected) in [
+            (1_234, "1234"),
+            (12_345, "1'2345"),
+            (12_345_678, "1234'5678"),
+            (123_456_789, "1'2345'6789"),
+        ] {
+            assert_eq!(
+                NearGas::from_gas(gas).to_grouped_string_with('\'', 4),
+                expected
+            );
+        }
+        assert_eq!(
+            NearGas::from_gas(1_234_567).to_grouped_string_with('\u{202f}', 3),
+            "1\u{202f}234\u{202f}567"
+        );
+        assert_eq!(
+            NearGas::from_gas(1_234_567).to_grouped_string_with(',', 0),
+            "1234567"
+        );
+    }
+
+    #[test]
+    fn for_iterations_gas() {
+        let fixed = NearGas::from_tgas(5);
+        assert_eq!(
+            NearGas::for_iterations(NearGas::from_tgas(1), 0, fixed),
+            Some(fixed)
+        );
+        assert_eq!(
+            NearGas::for_iterations(NearGas::from_ggas(100), 25, fixed),
+            Some(NearGas::from_ggas(7_500))
+        );
+        assert_eq!(
+            NearGas::for_iterations(NearGas::from_gas(1), u64::MAX, NearGas::from_gas(0)),
+            Some(NearGas::MAX)
+        );
+        assert_eq!(
+            NearGas::for_iterations(NearGas::from_gas(2), u64::MAX, NearGas::from_gas(0)),
+            None
+        );
+        assert_eq!(
+            NearGas::for_iterations(NearGas::from_gas(1), u64::MAX, NearGas::from_gas(1)),
+            None
+        );
+    }
+
+    #[test]
+    fn lerp_gas() {
+        let (start, end) = (NearGas::from_tgas(10), NearGas::from_tgas(30));
+        assert_eq!(NearGas::lerp(start, end, 0.0), start);
+        assert_eq!(NearGas::lerp(start, end, 0.5), NearGas::from_tgas(20));
+        assert_eq!(NearGas::lerp(start, end, 1.0), end);
+        assert_eq!(NearGas::lerp(end, start, 0.5), NearGas::from_tgas(20));
+        assert_eq!(NearGas::lerp(end, start, 1.0), start);
+        assert_eq!(NearGas::lerp(start, end, -1.0), start);
+        assert_eq!(NearGas::lerp(start, end, 2.0), end);
+        assert_eq!(NearGas::lerp(start, end, 1e30), end);
+        assert_eq!(NearGas::lerp(start, end, f64::INFINITY), end);
+        assert_eq!(NearGas::lerp(start, end, f64::NEG_INFINITY), start);
+        assert_eq!(NearGas::lerp(start, end, f64::NAN), start);
+        assert_eq!(NearGas::lerp(end, start, 1e30), start);
+        assert_eq!(NearGas::lerp(end, start, f64::INFINITY), start);
+        assert_eq!(NearGas::lerp(start, start, 0.5), start);
+        assert_eq!(
+            NearGas::lerp(NearGas::from_gas(0), NearGas::MAX, 1.0),
+            NearGas::MAX
+        );
+        assert_eq!(
+            NearGas::lerp(NearGas::MAX, NearGas::from_gas(0), 1.0),
+            NearGas::from_gas(0)
+        );
     }
 }
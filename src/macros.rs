@@ -0,0 +1,51 @@
+/// Defines a table of named `NearGas` constants from `"<number> <unit>"` literals,
+/// validated at compile time with [`NearGas::parse_const`](crate::NearGas::parse_const).
+///
+/// # Examples
+/// ```
+/// use near_gas::NearGas;
+///
+/// near_gas::gas_constants! {
+///     /// Gas for the cross-contract call.
+///     CALL_GAS = "30 Tgas",
+///     CALLBACK_GAS = "5.5 Tgas",
+/// }
+///
+/// assert_eq!(CALL_GAS, NearGas::from_tgas(30));
+/// assert_eq!(CALLBACK_GAS, NearGas::from_ggas(5500));
+/// ```
+///
+/// Invalid literals fail to compile:
+/// ```compile_fail,E0080
+/// near_gas::gas_constants! {
+///     CALL_GAS = "30 Pgas",
+/// }
+/// ```
+#[macro_export]
+macro_rules! gas_constants {
+    ($($(#[$attr:meta])* $name:ident = $value:literal),* $(,)?) => {
+        $(
+            $(#[$attr])*
+            pub const $name: $crate::NearGas = $crate::NearGas::parse_const($value);
+        )*
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use crate::NearGas;
+
+    crate::gas_constants! {
+        /// Documented constant.
+        FIRST = "1 Tgas",
+        SECOND = "250 Ggas",
+        THIRD = "42 gas"
+    }
+
+    #[test]
+    fn gas_constants() {
+        assert_eq!(FIRST, NearGas::from_tgas(1));
+        assert_eq!(SECOND, NearGas::from_ggas(250));
+        assert_eq!(THIRD, NearGas::from_gas(42));
+    }
+}
@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+use crate::NearGas;
+
+/// Map-shaped wrapper around `NearGas` that serializes as `{ "gas": "<gas>" }`.
+///
+/// `#[serde(flatten)]` only works with types that serialize as a map or a struct, so a bare
+/// `NearGas` (which serializes as a string) cannot be flattened into a parent struct.
+/// Wrapping it into `NearGasMap` makes the `gas` key appear directly in the parent object.
+///
+/// # Examples
+/// ```
+/// use near_gas::{NearGas, NearGasMap};
+///
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Receipt {
+///     id: u32,
+///     #[serde(flatten)]
+///     gas: NearGasMap,
+/// }
+///
+/// let receipt = Receipt { id: 1, gas: NearGas::from_tgas(5).into() };
+/// let json = serde_json::to_string(&receipt).unwrap();
+/// assert_eq!(json, r#"{"id":1,"gas":"5000000000000"}"#);
+/// let receipt: Receipt = serde_json::from_str(&json).unwrap();
+/// assert_eq!(receipt.gas.gas, NearGas::from_tgas(5));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct NearGasMap {
+    pub gas: NearGas,
+}
+
+impl From<NearGas> for NearGasMap {
+    fn from(gas: NearGas) -> Self {
+        NearGasMap { gas }
+    }
+}
+
+impl From<NearGasMap> for NearGas {
+    fn from(map: NearGasMap) -> Self {
+        map.gas
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::{Deserialize, Serialize};
+
+    use crate::{NearGas, NearGasMap};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Parent {
+        name: String,
+        #[serde(flatten)]
+        gas: NearGasMap,
+    }
+
+    #[test]
+    fn json_flatten_gas_map() {
+        let parent = Parent {
+            name: "transfer".to_owned(),
+            gas: NearGas::from_ggas(1500).into(),
+        };
+        let ser = serde_json::to_string(&parent).unwrap();
+        assert_eq!(ser, r#"{"name":"transfer","gas":"1500000000000"}"#);
+        let de: Parent = serde_json::from_str(&ser).unwrap();
+        assert_eq!(de, parent);
+        assert_eq!(NearGas::from(de.gas), NearGas::from_ggas(1500));
+    }
+}
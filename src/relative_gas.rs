@@ -0,0 +1,100 @@
+use crate::{DecimalNumberParsingError, NearGas, NearGasError};
+
+/// A gas budget that is either an absolute `NearGas` value or a percentage of some base
+/// (e.g. the prepaid gas), resolved later with [`RelativeGas::resolve`].
+///
+/// # Examples
+/// ```
+/// use near_gas::{NearGas, RelativeGas};
+///
+/// let absolute: RelativeGas = "5 Tgas".parse().unwrap();
+/// assert_eq!(absolute.resolve(NearGas::from_tgas(300)), NearGas::from_tgas(5));
+///
+/// let relative: RelativeGas = "80%".parse().unwrap();
+/// assert_eq!(relative.resolve(NearGas::from_tgas(300)), NearGas::from_tgas(240));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RelativeGas {
+    Absolute(NearGas),
+    /// Percentage of the base, in range `0..=100`.
+    Percent(u8),
+}
+
+impl RelativeGas {
+    /// Resolves the budget against `base`, rounding percentages down to whole gas.
+    pub const fn resolve(self, base: NearGas) -> NearGas {
+        match self {
+            RelativeGas::Absolute(gas) => gas,
+            RelativeGas::Percent(percent) => {
+                NearGas::from_gas((base.as_gas() as u128 * percent as u128 / 100) as u64)
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for RelativeGas {
+    type Err = NearGasError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some(percent) = s.trim().strip_suffix('%') else {
+            return s.parse().map(RelativeGas::Absolute);
+        };
+        percent
+            .trim()
+            .parse::<u8>()
+            .ok()
+            .filter(|percent| *percent <= 100)
+            .map(RelativeGas::Percent)
+            .ok_or_else(|| {
+                NearGasError::IncorrectNumber(DecimalNumberParsingError::InvalidNumber(
+                    percent.to_owned(),
+                ))
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use crate::{DecimalNumberParsingError, NearGas, NearGasError, RelativeGas};
+
+    #[test]
+    fn from_str_absolute() {
+        let gas = RelativeGas::from_str("10.5 Tgas").unwrap();
+        assert_eq!(gas, RelativeGas::Absolute(NearGas::from_ggas(10_500)));
+        assert_eq!(
+            gas.resolve(NearGas::from_tgas(300)),
+            NearGas::from_ggas(10_500)
+        );
+    }
+
+    #[test]
+    fn from_str_percent() {
+        let gas = RelativeGas::from_str("80%").unwrap();
+        assert_eq!(gas, RelativeGas::Percent(80));
+        assert_eq!(
+            gas.resolve(NearGas::from_tgas(300)),
+            NearGas::from_tgas(240)
+        );
+        assert_eq!(
+            gas.resolve(NearGas::from_gas(u64::MAX)),
+            NearGas::from_gas(u64::MAX / 5 * 4)
+        );
+        assert_eq!(
+            RelativeGas::from_str(" 100 % ").unwrap(),
+            RelativeGas::Percent(100)
+        );
+    }
+
+    #[test]
+    fn from_str_invalid_percent() {
+        for (data, number) in [("101%", "101"), ("-1%", "-1"), ("0.5%", "0.5"), ("%", "")] {
+            assert_eq!(
+                RelativeGas::from_str(data),
+                Err(NearGasError::IncorrectNumber(
+                    DecimalNumberParsingError::InvalidNumber(number.to_owned())
+                ))
+            );
+        }
+    }
+}
@@ -0,0 +1,86 @@
+//! Serde `with` module that (de)serializes `NearGas` as an array of 8 little-endian bytes,
+//! e.g. `[0, 16, 165, 212, 232, 0, 0, 0]` for 1 Tgas.
+//!
+//! # Examples
+//! ```
+//! use near_gas::NearGas;
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Config {
+//!     #[serde(with = "near_gas::serde_byte_array")]
+//!     prepaid_gas: NearGas,
+//! }
+//!
+//! let config: Config = serde_json::from_str(r#"{"prepaid_gas":[1,1,0,0,0,0,0,0]}"#).unwrap();
+//! assert_eq!(config.prepaid_gas, NearGas::from_gas(257));
+//! assert_eq!(
+//!     serde_json::to_string(&config).unwrap(),
+//!     r#"{"prepaid_gas":[1,1,0,0,0,0,0,0]}"#
+//! );
+//! ```
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::NearGas;
+
+/// Serializes `NearGas` as `[u8; 8]` in little-endian byte order.
+pub fn serialize<S>(gas: &NearGas, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    gas.to_le_bytes().serialize(serializer)
+}
+
+/// Deserializes `NearGas` from `[u8; 8]` in little-endian byte order.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<NearGas, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    <[u8; 8]>::deserialize(deserializer).map(NearGas::from_le_bytes)
+}
+
+#[cfg(test)]
+mod test {
+    use serde::{Deserialize, Serialize};
+
+    use crate::NearGas;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Config {
+        #[serde(with = "crate::serde_byte_array")]
+        gas: NearGas,
+    }
+
+    #[test]
+    fn json_byte_array() {
+        fn test_json(gas: NearGas, expected: &str) {
+            let ser = serde_json::to_string(&Config { gas }).unwrap();
+            assert_eq!(ser, format!("{{\"gas\":{}}}", expected));
+            let de: Config = serde_json::from_str(&ser).unwrap();
+            assert_eq!(de.gas, gas);
+        }
+
+        test_json(NearGas::from_gas(0), "[0,0,0,0,0,0,0,0]");
+        test_json(NearGas::from_gas(257), "[1,1,0,0,0,0,0,0]");
+        test_json(NearGas::from_tgas(1), "[0,16,165,212,232,0,0,0]");
+        test_json(
+            NearGas::from_gas(u64::MAX),
+            "[255,255,255,255,255,255,255,255]",
+        );
+    }
+
+    #[test]
+    fn json_byte_array_invalid() {
+        for json in [
+            r#"{"gas":[0,0,0,0,0,0,0]}"#,
+            r#"{"gas":[0,0,0,0,0,0,0,0,0]}"#,
+            r#"{"gas":[256,0,0,0,0,0,0,0]}"#,
+            r#"{"gas":"0"}"#,
+        ] {
+            assert!(
+                serde_json::from_str::<Config>(json).is_err(),
+                "json: {}",
+                json
+            );
+        }
+    }
+}
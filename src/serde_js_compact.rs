@@ -0,0 +1,77 @@
+//! Serde `with` module that serializes `NearGas` as a JSON number when it can be represented
+//! exactly by a JavaScript number (up to 2^53), and as a string otherwise.
+//!
+//! Deserialization accepts both forms.
+//!
+//! # Examples
+//! ```
+//! use near_gas::NearGas;
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Receipt {
+//!     #[serde(with = "near_gas::serde_js_compact")]
+//!     gas_burnt: NearGas,
+//! }
+//!
+//! let receipt = Receipt { gas_burnt: NearGas::from_tgas(5) };
+//! assert_eq!(serde_json::to_string(&receipt).unwrap(), r#"{"gas_burnt":5000000000000}"#);
+//!
+//! let receipt = Receipt { gas_burnt: NearGas::from_gas(u64::MAX) };
+//! assert_eq!(serde_json::to_string(&receipt).unwrap(), r#"{"gas_burnt":"18446744073709551615"}"#);
+//! ```
+use serde::{Deserialize, Deserializer, Serializer};
+
+use crate::NearGas;
+
+/// Integers up to 2^53 are represented exactly by JavaScript numbers.
+const MAX_JS_EXACT_INTEGER: u64 = 1 << 53;
+
+/// Serializes `NearGas` as a number up to 2^53, and as a string above it.
+pub fn serialize<S>(gas: &NearGas, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if gas.as_gas() <= MAX_JS_EXACT_INTEGER {
+        serializer.serialize_u64(gas.as_gas())
+    } else {
+        serializer.collect_str(&gas.as_gas())
+    }
+}
+
+/// Deserializes `NearGas` from either a number or a string of the number of gas.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<NearGas, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    NearGas::deserialize(deserializer)
+}
+
+#[cfg(test)]
+mod test {
+    use serde::{Deserialize, Serialize};
+
+    use crate::NearGas;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Receipt {
+        #[serde(with = "crate::serde_js_compact")]
+        gas: NearGas,
+    }
+
+    #[test]
+    fn json_ser_js_compact() {
+        fn test_json_ser(val: u64, expected: &str) {
+            let gas = NearGas::from_gas(val);
+            let ser = serde_json::to_string(&Receipt { gas }).unwrap();
+            assert_eq!(ser, format!("{{\"gas\":{}}}", expected));
+            let de: Receipt = serde_json::from_str(&ser).unwrap();
+            assert_eq!(de.gas, gas);
+        }
+
+        test_json_ser(0, "0");
+        test_json_ser((1 << 53) - 1, "9007199254740991");
+        test_json_ser(1 << 53, "9007199254740992");
+        test_json_ser((1 << 53) + 1, "\"9007199254740993\"");
+        test_json_ser(u64::MAX, "\"18446744073709551615\"");
+    }
+}
@@ -0,0 +1,76 @@
+//! Serde `with` module that (de)serializes `NearGas` as a decimal string of Tgas.
+//!
+//! # Examples
+//! ```
+//! use near_gas::NearGas;
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Config {
+//!     #[serde(with = "near_gas::serde_tgas")]
+//!     prepaid_gas: NearGas,
+//! }
+//!
+//! let config: Config = serde_json::from_str(r#"{"prepaid_gas":"1.5"}"#).unwrap();
+//! assert_eq!(config.prepaid_gas, NearGas::from_ggas(1500));
+//! assert_eq!(serde_json::to_string(&config).unwrap(), r#"{"prepaid_gas":"1.5"}"#);
+//! ```
+use serde::{de, Deserialize, Deserializer, Serializer};
+
+use crate::{NearGas, ONE_TERA_GAS};
+
+/// Serializes `NearGas` as a decimal string of Tgas without trailing zeros (e.g. `"1.5"`).
+pub fn serialize<S>(gas: &NearGas, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&crate::utils::format_decimal_number(
+        gas.as_gas(),
+        ONE_TERA_GAS,
+    ))
+}
+
+/// Deserializes `NearGas` from a decimal string of Tgas (e.g. `"1.5"`).
+pub fn deserialize<'de, D>(deserializer: D) -> Result<NearGas, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s: String = Deserialize::deserialize(deserializer)?;
+    crate::utils::parse_decimal_number(&s, ONE_TERA_GAS)
+        .map(NearGas::from_gas)
+        .map_err(|err| de::Error::custom(err.to_string()))
+}
+
+#[cfg(test)]
+mod test {
+    use serde::{Deserialize, Serialize};
+
+    use crate::NearGas;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Config {
+        #[serde(with = "crate::serde_tgas")]
+        gas: NearGas,
+    }
+
+    #[test]
+    fn json_ser_tgas() {
+        fn test_json_ser(gas: NearGas, expected: &str) {
+            let ser = serde_json::to_string(&Config { gas }).unwrap();
+            assert_eq!(ser, format!("{{\"gas\":\"{}\"}}", expected));
+            let de: Config = serde_json::from_str(&ser).unwrap();
+            assert_eq!(de.gas, gas);
+        }
+
+        test_json_ser(NearGas::from_gas(0), "0");
+        test_json_ser(NearGas::from_tgas(300), "300");
+        test_json_ser(NearGas::from_ggas(1500), "1.5");
+        test_json_ser(NearGas::from_gas(1), "0.000000000001");
+        test_json_ser(NearGas::from_gas(u64::MAX), "18446744.073709551615");
+    }
+
+    #[test]
+    fn json_de_tgas_invalid() {
+        assert!(serde_json::from_str::<Config>(r#"{"gas":"1.5 Tgas"}"#).is_err());
+        assert!(serde_json::from_str::<Config>(r#"{"gas":"0.0000000000001"}"#).is_err());
+    }
+}
@@ -0,0 +1,95 @@
+//! Serde `with` module that (de)serializes `NearGas` as a plain number of whole Tgas,
+//! e.g. `30` for 30 Tgas.
+//!
+//! Serialization fails if the gas is not a whole number of Tgas.
+//!
+//! # Examples
+//! ```
+//! use near_gas::NearGas;
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Config {
+//!     #[serde(with = "near_gas::serde_tgas_number")]
+//!     gas_tgas: NearGas,
+//! }
+//!
+//! let config: Config = serde_json::from_str(r#"{"gas_tgas":30}"#).unwrap();
+//! assert_eq!(config.gas_tgas, NearGas::from_tgas(30));
+//! assert_eq!(serde_json::to_string(&config).unwrap(), r#"{"gas_tgas":30}"#);
+//! ```
+use serde::{de, ser, Deserialize, Deserializer, Serializer};
+
+use crate::{NearGas, ONE_TERA_GAS};
+
+/// Serializes `NearGas` as a number of whole Tgas.
+pub fn serialize<S>(gas: &NearGas, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let tgas = gas
+        .require_whole_tgas()
+        .map_err(|err| ser::Error::custom(err.to_string()))?;
+    serializer.serialize_u64(tgas)
+}
+
+/// Deserializes `NearGas` from a number of whole Tgas.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<NearGas, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let tgas = u64::deserialize(deserializer)?;
+    tgas.checked_mul(ONE_TERA_GAS)
+        .map(NearGas::from_gas)
+        .ok_or_else(|| {
+            de::Error::invalid_value(
+                de::Unexpected::Unsigned(tgas),
+                &"a number of Tgas that fits into u64 gas",
+            )
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use serde::{Deserialize, Serialize};
+
+    use crate::NearGas;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Config {
+        #[serde(with = "crate::serde_tgas_number")]
+        gas: NearGas,
+    }
+
+    #[test]
+    fn json_tgas_number() {
+        for (json, expected) in [
+            (r#"{"gas":0}"#, NearGas::from_gas(0)),
+            (r#"{"gas":30}"#, NearGas::from_tgas(30)),
+            (r#"{"gas":18446744}"#, NearGas::from_tgas(18_446_744)),
+        ] {
+            let de: Config = serde_json::from_str(json).unwrap();
+            assert_eq!(de.gas, expected, "json: {}", json);
+            assert_eq!(serde_json::to_string(&de).unwrap(), json);
+        }
+    }
+
+    #[test]
+    fn json_tgas_number_invalid() {
+        for json in [
+            r#"{"gas":18446745}"#,
+            r#"{"gas":-1}"#,
+            r#"{"gas":1.5}"#,
+            r#"{"gas":"30"}"#,
+        ] {
+            assert!(
+                serde_json::from_str::<Config>(json).is_err(),
+                "json: {}",
+                json
+            );
+        }
+        assert!(serde_json::to_string(&Config {
+            gas: NearGas::from_ggas(1500)
+        })
+        .is_err());
+    }
+}
@@ -0,0 +1,85 @@
+//! Serde `with` module that (de)serializes `NearGas` as a plain `u64` number of gas.
+//!
+//! By default `NearGas` is serialized as a decimal string in every format. This module opts into
+//! the numeric form, which compact binary formats like `postcard` encode as a varint.
+//!
+//! # Examples
+//! ```
+//! use near_gas::NearGas;
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Config {
+//!     #[serde(with = "near_gas::serde_u64")]
+//!     prepaid_gas: NearGas,
+//! }
+//!
+//! let config: Config = serde_json::from_str(r#"{"prepaid_gas":300}"#).unwrap();
+//! assert_eq!(config.prepaid_gas, NearGas::from_gas(300));
+//! assert_eq!(serde_json::to_string(&config).unwrap(), r#"{"prepaid_gas":300}"#);
+//! ```
+use serde::{Deserialize, Deserializer, Serializer};
+
+use crate::NearGas;
+
+/// Serializes `NearGas` as a `u64` number of gas.
+pub fn serialize<S>(gas: &NearGas, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_u64(gas.as_gas())
+}
+
+/// Deserializes `NearGas` from a `u64` number of gas.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<NearGas, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    u64::deserialize(deserializer).map(NearGas::from_gas)
+}
+
+#[cfg(test)]
+mod test {
+    use serde::{Deserialize, Serialize};
+
+    use crate::NearGas;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Config {
+        #[serde(with = "crate::serde_u64")]
+        gas: NearGas,
+    }
+
+    #[test]
+    fn json_u64() {
+        for val in [0, 300, u64::MAX] {
+            let config = Config {
+                gas: NearGas::from_gas(val),
+            };
+            let ser = serde_json::to_string(&config).unwrap();
+            assert_eq!(ser, format!("{{\"gas\":{}}}", val));
+            assert_eq!(serde_json::from_str::<Config>(&ser).unwrap(), config);
+        }
+        assert!(serde_json::from_str::<Config>(r#"{"gas":"300"}"#).is_err());
+        assert!(serde_json::from_str::<Config>(r#"{"gas":-1}"#).is_err());
+    }
+
+    #[test]
+    fn postcard_u64() {
+        for val in [0, 8, 300, 300_000_000_000_000, u64::MAX] {
+            let config = Config {
+                gas: NearGas::from_gas(val),
+            };
+            let ser = postcard::to_allocvec(&config).unwrap();
+            // postcard encodes `u64` as unsigned LEB128.
+            assert_eq!(ser, config.gas.to_varint(), "gas: {}", val);
+            assert_eq!(postcard::from_bytes::<Config>(&ser).unwrap(), config);
+        }
+        assert_eq!(
+            postcard::to_allocvec(&Config {
+                gas: NearGas::from_gas(300)
+            })
+            .unwrap(),
+            [0xac, 0x02]
+        );
+    }
+}
@@ -0,0 +1,121 @@
+//! Serde `with` module that (de)serializes `NearGas` as an object with explicit value and unit,
+//! e.g. `{ "value": "1.5", "unit": "Tgas" }`.
+//!
+//! Any unit accepted by `NearGas::from_str` is supported on deserialization;
+//! serialization always uses Tgas.
+//!
+//! # Examples
+//! ```
+//! use near_gas::NearGas;
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Config {
+//!     #[serde(with = "near_gas::serde_value_unit")]
+//!     prepaid_gas: NearGas,
+//! }
+//!
+//! let config: Config =
+//!     serde_json::from_str(r#"{"prepaid_gas":{"value":"500","unit":"Ggas"}}"#).unwrap();
+//! assert_eq!(config.prepaid_gas, NearGas::from_ggas(500));
+//! assert_eq!(
+//!     serde_json::to_string(&config).unwrap(),
+//!     r#"{"prepaid_gas":{"value":"0.5","unit":"Tgas"}}"#
+//! );
+//! ```
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{NearGas, ONE_TERA_GAS};
+
+#[derive(Serialize, Deserialize)]
+struct ValueUnit {
+    value: String,
+    unit: String,
+}
+
+/// Serializes `NearGas` as `{ "value": "<Tgas>", "unit": "Tgas" }`.
+pub fn serialize<S>(gas: &NearGas, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    ValueUnit {
+        value: crate::utils::format_decimal_number(gas.as_gas(), ONE_TERA_GAS),
+        unit: "Tgas".to_owned(),
+    }
+    .serialize(serializer)
+}
+
+/// Deserializes `NearGas` from `{ "value": "<number>", "unit": "<unit>" }`.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<NearGas, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let ValueUnit { value, unit } = Deserialize::deserialize(deserializer)?;
+    format!("{} {}", value, unit)
+        .parse::<NearGas>()
+        .map_err(|err| de::Error::custom(err.to_string()))
+}
+
+#[cfg(test)]
+mod test {
+    use serde::{Deserialize, Serialize};
+
+    use crate::NearGas;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Config {
+        #[serde(with = "crate::serde_value_unit")]
+        gas: NearGas,
+    }
+
+    #[test]
+    fn json_de_value_unit() {
+        for (json, expected) in [
+            (
+                r#"{"gas":{"value":"1.5","unit":"Tgas"}}"#,
+                NearGas::from_ggas(1500),
+            ),
+            (
+                r#"{"gas":{"value":"300","unit":"TGAS"}}"#,
+                NearGas::from_tgas(300),
+            ),
+            (
+                r#"{"gas":{"value":"1.5","unit":"Ggas"}}"#,
+                NearGas::from_gas(1_500_000_000),
+            ),
+            (
+                r#"{"gas":{"value":"10","unit":"gigagas"}}"#,
+                NearGas::from_ggas(10),
+            ),
+        ] {
+            let de: Config = serde_json::from_str(json).unwrap();
+            assert_eq!(de.gas, expected, "json: {}", json);
+        }
+    }
+
+    #[test]
+    fn json_ser_value_unit() {
+        let ser = serde_json::to_string(&Config {
+            gas: NearGas::from_ggas(1500),
+        })
+        .unwrap();
+        assert_eq!(ser, r#"{"gas":{"value":"1.5","unit":"Tgas"}}"#);
+        let de: Config = serde_json::from_str(&ser).unwrap();
+        assert_eq!(de.gas, NearGas::from_ggas(1500));
+    }
+
+    #[test]
+    fn json_de_value_unit_invalid() {
+        for json in [
+            r#"{"gas":{"value":"1.5","unit":"pas"}}"#,
+            r#"{"gas":{"value":"1.5 Tgas","unit":"Tgas"}}"#,
+            r#"{"gas":{"value":"1.5"}}"#,
+            r#"{"gas":"1.5 Tgas"}"#,
+        ] {
+            assert!(
+                serde_json::from_str::<Config>(json).is_err(),
+                "json: {}",
+                json
+            );
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use crate::NearGas;
+
+impl AsRef<u64> for NearGas {
+    fn as_ref(&self) -> &u64 {
+        self.as_inner_ref()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::NearGas;
+
+    #[test]
+    fn as_ref_u64() {
+        fn total(values: &[impl AsRef<u64>]) -> u64 {
+            values.iter().map(|value| *value.as_ref()).sum()
+        }
+
+        assert_eq!(
+            total(&[NearGas::from_gas(1), NearGas::from_ggas(1)]),
+            1_000_000_001
+        );
+    }
+}
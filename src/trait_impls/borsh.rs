@@ -1,3 +1,40 @@
+use std::io::{Error, ErrorKind};
+
+use crate::NearGas;
+
+impl NearGas {
+    /// Decodes a borsh-encoded `Vec<u64>` (or `Vec<NearGas>`, which has the same layout)
+    /// directly into `Vec<NearGas>`, reading the packed little-endian values in one pass.
+    ///
+    /// Like `borsh::from_slice`, fails if `bytes` is truncated or has trailing bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// let bytes = borsh::to_vec(&vec![1u64, 1_000_000_000_000]).unwrap();
+    /// assert_eq!(
+    ///     NearGas::read_borsh_slice(&bytes).unwrap(),
+    ///     vec![NearGas::from_gas(1), NearGas::from_tgas(1)]
+    /// );
+    /// ```
+    pub fn read_borsh_slice(bytes: &[u8]) -> Result<Vec<NearGas>, Error> {
+        if bytes.len() < 4 {
+            return Err(ErrorKind::UnexpectedEof.into());
+        }
+        let (len, values) = bytes.split_at(4);
+        let len = u32::from_le_bytes(len.try_into().expect("4 bytes")) as usize;
+        match len.checked_mul(8) {
+            Some(size) if size == values.len() => {}
+            Some(size) if size > values.len() => return Err(ErrorKind::UnexpectedEof.into()),
+            _ => return Err(Error::new(ErrorKind::InvalidData, "Not all bytes read")),
+        }
+        Ok(values
+            .chunks_exact(8)
+            .map(|chunk| NearGas::from_le_bytes(chunk.try_into().expect("chunk of 8 bytes")))
+            .collect())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use borsh::{to_vec, BorshDeserialize};
@@ -19,4 +56,32 @@ mod test {
         test_borsh_ser(8, [8, 0, 0, 0, 0, 0, 0, 0]);
         test_borsh_ser(0, [0, 0, 0, 0, 0, 0, 0, 0]);
     }
+
+    #[test]
+    fn read_borsh_slice() {
+        let values = vec![0, 8, 1_000_000_000_000, u64::MAX];
+        let bytes = to_vec(&values).unwrap();
+        let gases = NearGas::read_borsh_slice(&bytes).unwrap();
+        assert_eq!(
+            gases,
+            values
+                .into_iter()
+                .map(NearGas::from_gas)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(Vec::<NearGas>::try_from_slice(&bytes).unwrap(), gases);
+
+        let empty = to_vec(&Vec::<u64>::new()).unwrap();
+        assert_eq!(NearGas::read_borsh_slice(&empty).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn read_borsh_slice_invalid() {
+        let bytes = to_vec(&vec![1u64, 2]).unwrap();
+        assert!(NearGas::read_borsh_slice(&bytes[..bytes.len() - 1]).is_err());
+        assert!(NearGas::read_borsh_slice(&bytes[..3]).is_err());
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(NearGas::read_borsh_slice(&trailing).is_err());
+    }
 }
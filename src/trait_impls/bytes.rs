@@ -0,0 +1,35 @@
+use crate::NearGas;
+
+/// Interprets the bytes as a little-endian `u64` number of gas, see [`NearGas::from_le_bytes`].
+impl From<[u8; 8]> for NearGas {
+    fn from(bytes: [u8; 8]) -> Self {
+        NearGas::from_le_bytes(bytes)
+    }
+}
+
+/// Encodes the number of gas as little-endian bytes, see [`NearGas::to_le_bytes`].
+impl From<NearGas> for [u8; 8] {
+    fn from(gas: NearGas) -> Self {
+        gas.to_le_bytes()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::NearGas;
+
+    #[test]
+    fn bytes_round_trip() {
+        fn test_bytes(val: u64, expected_bytes: [u8; 8]) {
+            let gas = NearGas::from_gas(val);
+            let bytes: [u8; 8] = gas.into();
+            assert_eq!(bytes, expected_bytes);
+            assert_eq!(NearGas::from(bytes), gas);
+        }
+
+        test_bytes(u64::MAX, [255, 255, 255, 255, 255, 255, 255, 255]);
+        test_bytes(8, [8, 0, 0, 0, 0, 0, 0, 0]);
+        test_bytes(256, [0, 1, 0, 0, 0, 0, 0, 0]);
+        test_bytes(0, [0, 0, 0, 0, 0, 0, 0, 0]);
+    }
+}
@@ -0,0 +1,36 @@
+use crate::NearGas;
+
+/// NearGas Debug implementation prints the exact number of gas annotated with its
+/// human-readable `Display` form, e.g. `NearGas(300000000000 gas /* 0.300 Tgas */)`.
+impl std::fmt::Debug for NearGas {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "NearGas({} gas /* {} */)", self.as_gas(), self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::NearGas;
+
+    #[test]
+    fn test_debug() {
+        for (near_gas, expected_debug) in [
+            (NearGas::from_gas(0), "NearGas(0 gas /* 0 Tgas */)"),
+            (NearGas::from_gas(1), "NearGas(1 gas /* <0.001 Tgas */)"),
+            (
+                NearGas::from_ggas(300),
+                "NearGas(300000000000 gas /* 0.300 Tgas */)",
+            ),
+            (
+                NearGas::from_tgas(300),
+                "NearGas(300000000000000 gas /* 300.0 Tgas */)",
+            ),
+        ] {
+            assert_eq!(format!("{:?}", near_gas), expected_debug);
+        }
+        assert_eq!(
+            format!("{:?}", Some(NearGas::from_gas(5))),
+            "Some(NearGas(5 gas /* <0.001 Tgas */))"
+        );
+    }
+}
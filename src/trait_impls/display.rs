@@ -1,4 +1,4 @@
-use crate::{NearGas, NearGasError, ONE_GIGA_GAS};
+use crate::{NearGas, NearGasError, MAX_TRANSACTION_GAS, ONE_GIGA_GAS};
 
 /// NearGas Display implementation rounds up the gas usage to the relevant precision point.
 /// There are 4 breakpoints:
@@ -6,24 +6,25 @@ use crate::{NearGas, NearGasError, ONE_GIGA_GAS};
 /// 2. <0.001 Tgas
 /// 3. 0.001 - 0.999 Tgas (uses 3 digits after the floating point)
 /// 4. >1 Tgas (uses 1 digit after the floating point)
+///
+/// Width, fill, and alignment flags are applied to the whole rendered value (e.g. `{:>12}`).
 impl std::fmt::Display for NearGas {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if *self == NearGas::from_gas(0) {
-            write!(f, "0 Tgas")
+            f.pad("0 Tgas")
         } else if *self < NearGas::from_ggas(1) {
-            write!(f, "<0.001 Tgas")
+            f.pad("<0.001 Tgas")
         } else if *self <= NearGas::from_ggas(999) {
             let gigagas_rounded_up = self.as_gas().saturating_add(ONE_GIGA_GAS - 1) / ONE_GIGA_GAS;
-            write!(f, "0.{:03} Tgas", gigagas_rounded_up)
+            f.pad(&format!("0.{:03} Tgas", gigagas_rounded_up))
         } else {
             let terragas_rounded_up =
                 self.as_gas().saturating_add(100 * ONE_GIGA_GAS - 1) / ONE_GIGA_GAS / 100;
-            write!(
-                f,
+            f.pad(&format!(
                 "{}.{} Tgas",
                 terragas_rounded_up / 10,
                 terragas_rounded_up % 10
-            )
+            ))
         }
     }
 }
@@ -32,7 +33,30 @@ impl std::fmt::Display for NearGasError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             NearGasError::IncorrectNumber(err) => write!(f, "Incorrect number: {:?}", err),
-            NearGasError::IncorrectUnit(err) => write!(f, "Incorrect unit: {}", err),
+            NearGasError::IncorrectUnit {
+                input,
+                supported_units,
+            } => write!(
+                f,
+                "Incorrect unit: {} (supported units: {})",
+                input,
+                supported_units.join(", ")
+            ),
+            NearGasError::ExceedsTransactionLimit(gas) => write!(
+                f,
+                "Exceeds transaction limit: {} (max {})",
+                gas,
+                NearGas::from_gas(MAX_TRANSACTION_GAS)
+            ),
+            NearGasError::InvalidListElement { index, error } => {
+                write!(f, "Invalid element at index {}: {}", index, error)
+            }
+            NearGasError::InvalidVarint(bytes) => write!(f, "Invalid varint: {:?}", bytes),
+            NearGasError::InvalidAssignment { input, key } => {
+                write!(f, "Invalid assignment: {} (expected {}=<gas>)", input, key)
+            }
+            NearGasError::NotWholeTgas(gas) => write!(f, "Not a whole number of Tgas: {}", gas),
+            NearGasError::SumOverflow(input) => write!(f, "Sum of gas overflows: {}", input),
         }
     }
 }
@@ -77,4 +101,23 @@ mod test {
             );
         }
     }
+
+    #[test]
+    fn test_display_incorrect_unit() {
+        let err = "100 UAH".parse::<NearGas>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Incorrect unit: 100 UAH (supported units: Tgas, TeraGas, T, Ggas, GigaGas, G, gas)"
+        );
+    }
+
+    #[test]
+    fn test_display_padding() {
+        let gas = NearGas::from_tgas(1);
+        assert_eq!(format!("{:>20}", gas), "            1.0 Tgas");
+        assert_eq!(format!("{:<12}|", gas), "1.0 Tgas    |");
+        assert_eq!(format!("{:*^12}", gas), "**1.0 Tgas**");
+        assert_eq!(format!("{:>4}", gas), "1.0 Tgas");
+        assert_eq!(format!("{:>8}", NearGas::from_gas(0)), "  0 Tgas");
+    }
 }
@@ -1,25 +1,355 @@
+use std::ops::Range;
+
 use crate::{NearGas, NearGasError, ONE_GIGA_GAS, ONE_TERA_GAS};
 
+/// Parses `NearGas` from a decimal number and one of [`NearGas::SUPPORTED_UNITS`], e.g. `"1.5 Tgas"`.
+///
+/// The unit normally follows the number, with optional whitespace in between ("5 Tgas", "5Tgas").
+/// The unit may also come first ("Tgas 5"), but only if the input does not end with a letter:
+/// a trailing run of letters always takes precedence as the unit, so "Tgas 5 Tgas" is parsed
+/// as the number "Tgas 5" in Tgas and fails.
+///
+/// # Examples
+/// ```
+/// use near_gas::NearGas;
+/// assert_eq!("5 Tgas".parse::<NearGas>(), Ok(NearGas::from_tgas(5)));
+/// assert_eq!("Tgas 5".parse::<NearGas>(), Ok(NearGas::from_tgas(5)));
+/// assert!("Tgas 5 Tgas".parse::<NearGas>().is_err());
+/// ```
 impl std::str::FromStr for NearGas {
     type Err = NearGasError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let uppercase_s = s.trim().to_ascii_uppercase();
-        let (value, unit) = uppercase_s.split_at(
-            s.find(|c: char| c.is_ascii_alphabetic())
-                .ok_or_else(|| NearGasError::IncorrectUnit(s.to_owned()))?,
-        );
-        let unit_precision = match unit {
-            "TGAS" | "TERAGAS" => ONE_TERA_GAS,
-            "GIGAGAS" | "GGAS" => ONE_GIGA_GAS,
-            _ => return Err(NearGasError::IncorrectUnit(s.to_owned())),
+        let (value, unit_precision) = split_value_and_unit(s)?;
+        Ok(NearGas::from_gas(crate::utils::parse_decimal_number(
+            value.trim(),
+            unit_precision,
+        )?))
+    }
+}
+
+impl NearGas {
+    /// Units accepted when parsing `NearGas` from a string (case-insensitive, underscores are ignored).
+    ///
+    /// `T` and `G` are short aliases for Tgas and Ggas (e.g. `"1.5T"`). Of the suffixes printed by
+    /// [`NearGas::to_si_string`], only `T` and `G` are accepted, and since it rounds to one
+    /// decimal, parsing its output back is lossy.
+    pub const SUPPORTED_UNITS: &'static [&'static str] =
+        &["Tgas", "TeraGas", "T", "Ggas", "GigaGas", "G", "gas"];
+
+    /// Parses `NearGas` like [`std::str::FromStr`], but instead of failing on fractional digits
+    /// beyond the precision of the unit, drops them and reports whether any non-zero digit was lost.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_str_lossy("1.5 Ggas"), Ok((NearGas::from_gas(1_500_000_000), false)));
+    /// assert_eq!(NearGas::from_str_lossy("1.5 gas"), Ok((NearGas::from_gas(1), true)));
+    /// ```
+    pub fn from_str_lossy(s: &str) -> Result<(NearGas, bool), NearGasError> {
+        let (value, unit_precision) = split_value_and_unit(s)?;
+        let (gas, was_truncated) =
+            crate::utils::parse_decimal_number_lossy(value.trim(), unit_precision)?;
+        Ok((NearGas::from_gas(gas), was_truncated))
+    }
+
+    /// Parses a sequence of `<number> <unit>` components and sums them up,
+    /// e.g. `"1 Tgas 500 Ggas 42 gas"` or `"1Tgas 500Ggas"`.
+    ///
+    /// Each component is a number followed by its unit, the whitespace between the number and
+    /// the unit as well as between components is optional.
+    ///
+    /// Returns `NearGasError::SumOverflow` if the sum does not fit into `u64`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(
+    ///     NearGas::from_compound_str("1 Tgas 500 Ggas 42 gas"),
+    ///     Ok(NearGas::from_gas(1_500_000_000_042))
+    /// );
+    /// assert_eq!(NearGas::from_compound_str("1Tgas 500Ggas"), Ok(NearGas::from_ggas(1500)));
+    /// assert!(NearGas::from_compound_str("1 Tgas 500").is_err());
+    /// ```
+    pub fn from_compound_str(s: &str) -> Result<NearGas, NearGasError> {
+        let mut rest = s.trim_start();
+        if rest.is_empty() {
+            return s.parse();
+        }
+        let mut total = NearGas::from_gas(0);
+        while !rest.is_empty() {
+            let unit_start = rest
+                .find(|c: char| c.is_ascii_alphabetic())
+                .unwrap_or(rest.len());
+            let unit_end = rest[unit_start..]
+                .find(|c: char| !(c.is_ascii_alphabetic() || c == '_'))
+                .map_or(rest.len(), |len| unit_start + len);
+            total = total
+                .checked_add(rest[..unit_end].parse()?)
+                .ok_or_else(|| NearGasError::SumOverflow(s.to_owned()))?;
+            rest = rest[unit_end..].trim_start();
+        }
+        Ok(total)
+    }
+
+    /// Parses `NearGas` like [`std::str::FromStr`], but on error also returns the byte range
+    /// of the offending token (the number or the unit) in `s`, e.g. to underline it in an editor.
+    ///
+    /// If the unit is missing, the range is empty and points at the end of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::from_str_spanned("1.5 Tgas"), Ok(NearGas::from_ggas(1500)));
+    /// assert_eq!(NearGas::from_str_spanned("1.5 Tgsa").unwrap_err().1, 4..8);
+    /// assert_eq!(NearGas::from_str_spanned("1.x5 Tgas").unwrap_err().1, 0..4);
+    /// ```
+    pub fn from_str_spanned(s: &str) -> Result<NearGas, (NearGasError, Range<usize>)> {
+        let (value, unit_precision) = split_value_and_unit_spanned(s)?;
+        crate::utils::parse_decimal_number(&s[value.clone()], unit_precision)
+            .map(NearGas::from_gas)
+            .map_err(|error| (error.into(), value))
+    }
+
+    /// Parses `NearGas` from an environment-variable style assignment `"<key>=<gas>"`,
+    /// e.g. `"NEAR_GAS=30 Tgas"`.
+    ///
+    /// Returns `NearGasError::InvalidAssignment` if there is no `=` or the key does not match.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(
+    ///     NearGas::from_env_assignment("NEAR_GAS=30 Tgas", "NEAR_GAS"),
+    ///     Ok(NearGas::from_tgas(30))
+    /// );
+    /// assert!(NearGas::from_env_assignment("OTHER_GAS=30 Tgas", "NEAR_GAS").is_err());
+    /// ```
+    pub fn from_env_assignment(s: &str, key: &str) -> Result<NearGas, NearGasError> {
+        match s.split_once('=') {
+            Some((actual_key, value)) if actual_key.trim() == key => value.parse(),
+            _ => Err(NearGasError::InvalidAssignment {
+                input: s.to_owned(),
+                key: key.to_owned(),
+            }),
+        }
+    }
+
+    /// Parses `NearGas` from `"<number> <unit>"` in const contexts, e.g. `"5 Tgas"` or `"1.5Ggas"`.
+    ///
+    /// Accepts the same numbers and units as [`std::str::FromStr`], with a few exceptions that
+    /// are rejected here: a leading `+` sign, underscores in the unit (`"5 Tera_Gas"`), the unit
+    /// before the number (`"Tgas 5"`) and non-ASCII whitespace. Panics on invalid input, which
+    /// is a compile-time error when evaluated in a `const` item. See also
+    /// [`gas_constants!`](crate::gas_constants).
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// const GAS: NearGas = NearGas::parse_const("1.5 Tgas");
+    /// assert_eq!(GAS, NearGas::from_ggas(1500));
+    /// ```
+    pub const fn parse_const(s: &str) -> NearGas {
+        let bytes = s.as_bytes();
+        let mut start = 0;
+        let mut end = bytes.len();
+        while start < end && bytes[start].is_ascii_whitespace() {
+            start += 1;
+        }
+        while end > start && bytes[end - 1].is_ascii_whitespace() {
+            end -= 1;
+        }
+        let mut unit_start = end;
+        while unit_start > start && bytes[unit_start - 1].is_ascii_alphabetic() {
+            unit_start -= 1;
+        }
+        let unit_precision = if unit_eq_const(bytes, unit_start, end, b"TGAS")
+            || unit_eq_const(bytes, unit_start, end, b"TERAGAS")
+            || unit_eq_const(bytes, unit_start, end, b"T")
+        {
+            ONE_TERA_GAS
+        } else if unit_eq_const(bytes, unit_start, end, b"GGAS")
+            || unit_eq_const(bytes, unit_start, end, b"GIGAGAS")
+            || unit_eq_const(bytes, unit_start, end, b"G")
+        {
+            ONE_GIGA_GAS
+        } else if unit_eq_const(bytes, unit_start, end, b"GAS") {
+            1
+        } else {
+            panic!("incorrect gas unit")
         };
-        Ok(NearGas::from_gas(
-            crate::utils::parse_decimal_number(value.trim(), unit_precision)
-                .map_err(NearGasError::IncorrectNumber)?,
-        ))
+        let mut number_end = unit_start;
+        while number_end > start && bytes[number_end - 1].is_ascii_whitespace() {
+            number_end -= 1;
+        }
+
+        let mut index = start;
+        let mut whole: u64 = 0;
+        while index < number_end && bytes[index] != b'.' {
+            whole = match whole.checked_mul(10) {
+                Some(whole) => match whole.checked_add(digit_const(bytes[index])) {
+                    Some(whole) => whole,
+                    None => panic!("gas number is too large"),
+                },
+                None => panic!("gas number is too large"),
+            };
+            index += 1;
+        }
+        if index == start {
+            panic!("invalid gas number");
+        }
+        let mut gas = match whole.checked_mul(unit_precision) {
+            Some(gas) => gas,
+            None => panic!("gas number is too large"),
+        };
+        if index < number_end {
+            // Skip the dot, the fractional part must not be empty.
+            index += 1;
+            if index == number_end {
+                panic!("invalid gas number");
+            }
+            let mut scale = unit_precision;
+            while index < number_end {
+                scale /= 10;
+                if scale == 0 {
+                    panic!("too many fractional digits for the gas unit");
+                }
+                gas = match gas.checked_add(digit_const(bytes[index]) * scale) {
+                    Some(gas) => gas,
+                    None => panic!("gas number is too large"),
+                };
+                index += 1;
+            }
+        }
+        NearGas::from_gas(gas)
+    }
+
+    /// Parses `NearGas` like [`std::str::FromStr`], panicking with the offending input on failure.
+    ///
+    /// Intended for tests and other places where the input is known to be valid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `s` is not a valid amount of gas.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(NearGas::expect_from_str("30 Tgas"), NearGas::from_tgas(30));
+    /// ```
+    #[track_caller]
+    pub fn expect_from_str(s: &str) -> NearGas {
+        match s.parse() {
+            Ok(gas) => gas,
+            Err(err) => panic!("invalid gas {:?}: {}", s, err),
+        }
+    }
+
+    /// Parses a list of `NearGas` separated by `delimiter`, e.g. `"30 Tgas,50 Tgas,100 Ggas"`.
+    ///
+    /// The first invalid element is reported as `NearGasError::InvalidListElement` with its index.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert_eq!(
+    ///     NearGas::parse_list("30 Tgas,100 Ggas", ','),
+    ///     Ok(vec![NearGas::from_tgas(30), NearGas::from_ggas(100)])
+    /// );
+    /// ```
+    pub fn parse_list(s: &str, delimiter: char) -> Result<Vec<NearGas>, NearGasError> {
+        s.split(delimiter)
+            .enumerate()
+            .map(|(index, element)| {
+                element
+                    .parse()
+                    .map_err(|error| NearGasError::InvalidListElement {
+                        index,
+                        error: Box::new(error),
+                    })
+            })
+            .collect()
     }
 }
 
+/// Compares `bytes[start..end]` to the uppercase `unit` ignoring ASCII case, in const contexts.
+const fn unit_eq_const(bytes: &[u8], start: usize, end: usize, unit: &[u8]) -> bool {
+    if end - start != unit.len() {
+        return false;
+    }
+    let mut index = 0;
+    while index < unit.len() {
+        if bytes[start + index].to_ascii_uppercase() != unit[index] {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
+
+/// Converts an ASCII digit to its value, panicking on any other byte.
+const fn digit_const(byte: u8) -> u64 {
+    if !byte.is_ascii_digit() {
+        panic!("invalid gas number");
+    }
+    (byte - b'0') as u64
+}
+
+/// Splits the input into the number part and the precision of its unit.
+fn split_value_and_unit(s: &str) -> Result<(&str, u64), NearGasError> {
+    split_value_and_unit_spanned(s)
+        .map(|(value, unit_precision)| (&s[value], unit_precision))
+        .map_err(|(error, _)| error)
+}
+
+/// Splits the input into the byte range of the (trimmed) number part and the precision of its unit.
+/// On error, also returns the byte range of the offending unit (empty at the end of the input if
+/// there is no unit at all).
+///
+/// See the `FromStr` implementation for the precedence of unit-last over unit-first input.
+fn split_value_and_unit_spanned(
+    s: &str,
+) -> Result<(Range<usize>, u64), (NearGasError, Range<usize>)> {
+    let trimmed = s.trim();
+    let offset = s.len() - s.trim_start().len();
+    let is_unit_char = |c: char| c.is_ascii_alphabetic() || c == '_';
+    let (value, unit) = if trimmed.ends_with(|c: char| c.is_ascii_alphabetic()) {
+        let unit_start = trimmed
+            .char_indices()
+            .rev()
+            .find(|(_, c)| !is_unit_char(*c))
+            .map_or(0, |(index, c)| index + c.len_utf8());
+        (0..unit_start, unit_start..trimmed.len())
+    } else if trimmed.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        let unit_end = trimmed
+            .find(|c: char| !is_unit_char(c))
+            .unwrap_or(trimmed.len());
+        (unit_end..trimmed.len(), 0..unit_end)
+    } else {
+        let end = offset + trimmed.len();
+        return Err((NearGasError::incorrect_unit(s), end..end));
+    };
+    let value = trim_range(s, offset + value.start..offset + value.end);
+    let unit = offset + unit.start..offset + unit.end;
+    let mut unit_name = s[unit.clone()].to_ascii_uppercase();
+    // Underscores are ignored in the unit (e.g. "Tera_Gas"), but not in the number.
+    unit_name.retain(|c| c != '_');
+    let unit_precision = match unit_name.as_str() {
+        "TGAS" | "TERAGAS" | "T" => ONE_TERA_GAS,
+        "GIGAGAS" | "GGAS" | "G" => ONE_GIGA_GAS,
+        // Gas is indivisible, so any fractional part is rejected as `LongFractional`.
+        "GAS" => 1,
+        _ => return Err((NearGasError::incorrect_unit(s), unit)),
+    };
+    Ok((value, unit_precision))
+}
+
+/// Narrows the byte range of `s` to exclude leading and trailing whitespace.
+fn trim_range(s: &str, range: Range<usize>) -> Range<usize> {
+    let part = &s[range.clone()];
+    let start = range.start + part.len() - part.trim_start().len();
+    start..start + part.trim().len()
+}
+
 #[cfg(test)]
 mod test {
     use std::str::FromStr;
@@ -61,14 +391,14 @@ mod test {
     fn incorect_currency() {
         let data = "0 pas";
         let gas: Result<NearGas, NearGasError> = FromStr::from_str(data);
-        assert_eq!(gas, Err(NearGasError::IncorrectUnit(data.to_owned())))
+        assert_eq!(gas, Err(NearGasError::incorrect_unit(data)))
     }
 
     #[test]
     fn without_currency() {
         let data = "0";
         let gas: Result<NearGas, NearGasError> = FromStr::from_str(data);
-        assert_eq!(gas, Err(NearGasError::IncorrectUnit("0".to_owned())))
+        assert_eq!(gas, Err(NearGasError::incorrect_unit("0")))
     }
 
     #[test]
@@ -97,19 +427,25 @@ mod test {
     #[test]
     fn test_from_str_without_unit() {
         let near_gas = NearGas::from_str("100").unwrap_err();
-        assert_eq!(near_gas, NearGasError::IncorrectUnit("100".to_string()));
+        assert_eq!(near_gas, NearGasError::incorrect_unit("100"));
     }
 
     #[test]
     fn test_from_str_incorrect_unit() {
         let near_gas = NearGas::from_str("100 UAH").unwrap_err();
-        assert_eq!(near_gas, NearGasError::IncorrectUnit("100 UAH".to_string()));
+        assert_eq!(
+            near_gas,
+            NearGasError::IncorrectUnit {
+                input: "100 UAH".to_string(),
+                supported_units: &["Tgas", "TeraGas", "T", "Ggas", "GigaGas", "G", "gas"],
+            }
+        );
     }
 
     #[test]
     fn test_from_str_invalid_double_dot() {
         let near_gas = NearGas::from_str("100.55.").unwrap_err();
-        assert_eq!(near_gas, NearGasError::IncorrectUnit("100.55.".to_string()));
+        assert_eq!(near_gas, NearGasError::incorrect_unit("100.55."));
     }
 
     #[test]
@@ -134,6 +470,17 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_from_str_plus_sign() {
+        assert_eq!(NearGas::from_str("+5 Tgas").unwrap(), NearGas::from_tgas(5));
+        assert_eq!(
+            NearGas::from_str("-5 Tgas").unwrap_err(),
+            NearGasError::IncorrectNumber(DecimalNumberParsingError::InvalidNumber(
+                "-5".to_string()
+            ))
+        );
+    }
+
     #[test]
     fn test_from_str_negative_value() {
         let near_gas = NearGas::from_str("-100 ggas").unwrap_err();
@@ -169,6 +516,105 @@ mod test {
         );
     }
 
+    #[test]
+    fn near_gas_from_str_underscore_unit() {
+        assert_eq!(
+            NearGas::from_str("1 Tera_Gas").unwrap(),
+            NearGas::from_tgas(1)
+        );
+        assert_eq!(
+            NearGas::from_str("1 Giga_Gas").unwrap(),
+            NearGas::from_ggas(1)
+        );
+        assert_eq!(
+            NearGas::from_str("1.5 T_GAS").unwrap(),
+            NearGas::from_ggas(1500)
+        );
+        assert_eq!(
+            NearGas::from_str("1_000 Tgas").unwrap_err(),
+            NearGasError::IncorrectNumber(DecimalNumberParsingError::InvalidNumber(
+                "1_000".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn near_gas_from_str_unit_first() {
+        assert_eq!(NearGas::from_str("Tgas 5").unwrap(), NearGas::from_tgas(5));
+        assert_eq!(NearGas::from_str("5 Tgas").unwrap(), NearGas::from_tgas(5));
+        assert_eq!(
+            NearGas::from_str(" ggas 1.5 ").unwrap(),
+            NearGas::from_gas(1_500_000_000)
+        );
+        assert_eq!(
+            NearGas::from_str("Tera_Gas 1").unwrap(),
+            NearGas::from_tgas(1)
+        );
+        assert_eq!(
+            NearGas::from_str("Tgas 5 Tgas").unwrap_err(),
+            NearGasError::IncorrectNumber(DecimalNumberParsingError::InvalidNumber(
+                "Tgas 5".to_string()
+            ))
+        );
+        assert_eq!(
+            NearGas::from_str("Tgas").unwrap_err(),
+            NearGasError::IncorrectNumber(DecimalNumberParsingError::EmptyInput)
+        );
+        assert_eq!(
+            NearGas::from_str("pas 5").unwrap_err(),
+            NearGasError::incorrect_unit("pas 5")
+        );
+    }
+
+    #[test]
+    fn near_gas_from_str_float_artifacts() {
+        for (data, number) in [
+            ("inf Tgas", "inf"),
+            ("NaN Tgas", "NaN"),
+            ("1e3 Ggas", "1e3"),
+        ] {
+            assert_eq!(
+                NearGas::from_str(data).unwrap_err(),
+                NearGasError::IncorrectNumber(DecimalNumberParsingError::InvalidNumber(
+                    number.to_string()
+                ))
+            );
+        }
+    }
+
+    #[test]
+    fn near_gas_from_str_non_ascii() {
+        assert_eq!(
+            NearGas::from_str("5 ΩTgas").unwrap_err(),
+            NearGasError::IncorrectNumber(DecimalNumberParsingError::InvalidNumber(
+                "5 Ω".to_string()
+            ))
+        );
+        assert_eq!(
+            NearGas::from_str("5 Tgasω").unwrap_err(),
+            NearGasError::incorrect_unit("5 Tgasω")
+        );
+    }
+
+    #[test]
+    fn near_gas_from_str_leading_whitespace() {
+        assert_eq!(
+            NearGas::from_str("  5 Tgas").unwrap(),
+            NearGas::from_tgas(5)
+        );
+    }
+
+    #[test]
+    fn near_gas_from_str_gas() {
+        assert_eq!(NearGas::from_str("5 gas").unwrap(), NearGas::from_gas(5));
+        assert_eq!(
+            NearGas::from_str("1.5 gas").unwrap_err(),
+            NearGasError::IncorrectNumber(DecimalNumberParsingError::LongFractional(
+                "5".to_string()
+            ))
+        );
+    }
+
     #[test]
     fn near_gas_from_str_f64_tgas() {
         assert_eq!(
@@ -176,4 +622,360 @@ mod test {
             NearGas::from_gas(1_000_000) // 7 digits
         );
     }
+
+    #[test]
+    fn from_str_lossy() {
+        for (data, expected_gas, expected_truncated) in [
+            ("1.5 tgas", 1_500_000_000_000, false),
+            ("10 ggas", 10_000_000_000, false),
+            ("1.000000000000000 tgas", 1_000_000_000_000, false),
+            ("0.1234567891239 tgas", 123_456_789_123, true),
+            ("1.0000000001 ggas", 1_000_000_000, true),
+            ("1.5 gas", 1, true),
+            ("7.0 gas", 7, false),
+        ] {
+            assert_eq!(
+                NearGas::from_str_lossy(data),
+                Ok((NearGas::from_gas(expected_gas), expected_truncated)),
+                "data: {}",
+                data
+            );
+        }
+    }
+
+    #[test]
+    fn from_str_lossy_invalid() {
+        assert_eq!(
+            NearGas::from_str_lossy("1.00000000001x ggas"),
+            Err(NearGasError::IncorrectNumber(
+                DecimalNumberParsingError::InvalidNumber("1.00000000001x".to_owned())
+            ))
+        );
+        assert_eq!(
+            NearGas::from_str_lossy("1.0000000000-1 ggas"),
+            Err(NearGasError::IncorrectNumber(
+                DecimalNumberParsingError::InvalidNumber("1.0000000000-1".to_owned())
+            ))
+        );
+        assert_eq!(
+            NearGas::from_str_lossy(".5 gas"),
+            Err(NearGasError::IncorrectNumber(
+                DecimalNumberParsingError::InvalidNumber(".5".to_owned())
+            ))
+        );
+    }
+
+    #[test]
+    fn from_compound_str() {
+        assert_eq!(
+            NearGas::from_compound_str("1 Tgas 500 Ggas 42 gas"),
+            Ok(NearGas::from_gas(1_500_000_000_042))
+        );
+        assert_eq!(
+            NearGas::from_compound_str(" 0.5 tgas\t250 GGAS "),
+            Ok(NearGas::from_ggas(750))
+        );
+        assert_eq!(
+            NearGas::from_compound_str("5 Tgas"),
+            Ok(NearGas::from_tgas(5))
+        );
+        assert_eq!(
+            NearGas::from_compound_str("1Tgas 500Ggas"),
+            Ok(NearGas::from_ggas(1500))
+        );
+        assert_eq!(
+            NearGas::from_compound_str("1Tgas500Ggas 1 gas"),
+            Ok(NearGas::from_gas(1_500_000_000_001))
+        );
+    }
+
+    #[test]
+    fn from_compound_str_invalid() {
+        assert_eq!(
+            NearGas::from_compound_str("1 Tgas 500"),
+            Err(NearGasError::incorrect_unit("500"))
+        );
+        assert_eq!(
+            NearGas::from_compound_str("1 Tgas 500 UAH"),
+            Err(NearGasError::incorrect_unit("500 UAH"))
+        );
+        assert_eq!(
+            NearGas::from_compound_str(""),
+            Err(NearGasError::incorrect_unit(""))
+        );
+        assert_eq!(
+            NearGas::from_compound_str("1 Tgas Ggas 500"),
+            Err(NearGasError::IncorrectNumber(
+                DecimalNumberParsingError::EmptyInput
+            ))
+        );
+        assert_eq!(
+            NearGas::from_compound_str("Tgas 1 Ggas 2"),
+            Err(NearGasError::IncorrectNumber(
+                DecimalNumberParsingError::EmptyInput
+            ))
+        );
+        let data = "18446744 Tgas 73709551615 gas 1 gas";
+        assert_eq!(
+            NearGas::from_compound_str(data),
+            Err(NearGasError::SumOverflow(data.to_owned()))
+        );
+        assert_eq!(
+            NearGas::from_compound_str("18446744 Tgas 73709551615 gas"),
+            Ok(NearGas::MAX)
+        );
+    }
+
+    #[test]
+    fn parse_list() {
+        assert_eq!(
+            NearGas::parse_list("30 Tgas,50 Tgas, 100 Ggas", ','),
+            Ok(vec![
+                NearGas::from_tgas(30),
+                NearGas::from_tgas(50),
+                NearGas::from_ggas(100)
+            ])
+        );
+        assert_eq!(
+            NearGas::parse_list("5 Tgas", ';'),
+            Ok(vec![NearGas::from_tgas(5)])
+        );
+    }
+
+    #[test]
+    fn parse_list_invalid_element() {
+        assert_eq!(
+            NearGas::parse_list("30 Tgas;50 UAH;1.1.1 Tgas", ';'),
+            Err(NearGasError::InvalidListElement {
+                index: 1,
+                error: Box::new(NearGasError::incorrect_unit("50 UAH")),
+            })
+        );
+        assert_eq!(
+            NearGas::parse_list("30 Tgas,", ','),
+            Err(NearGasError::InvalidListElement {
+                index: 1,
+                error: Box::new(NearGasError::incorrect_unit("")),
+            })
+        );
+    }
+
+    #[test]
+    fn from_str_spanned() {
+        assert_eq!(
+            NearGas::from_str_spanned("  10 Tgas "),
+            Ok(NearGas::from_tgas(10))
+        );
+        assert_eq!(
+            NearGas::from_str_spanned(" 1.1.1 Tgas"),
+            Err((
+                NearGasError::IncorrectNumber(DecimalNumberParsingError::InvalidNumber(
+                    "1.1.1".to_owned()
+                )),
+                1..6
+            ))
+        );
+        assert_eq!(
+            NearGas::from_str_spanned("Tgas 1.1.1"),
+            Err((
+                NearGasError::IncorrectNumber(DecimalNumberParsingError::InvalidNumber(
+                    "1.1.1".to_owned()
+                )),
+                5..10
+            ))
+        );
+        assert_eq!(
+            NearGas::from_str_spanned("10 Pgas"),
+            Err((NearGasError::incorrect_unit("10 Pgas"), 3..7))
+        );
+        assert_eq!(
+            NearGas::from_str_spanned("10 "),
+            Err((NearGasError::incorrect_unit("10 "), 2..2))
+        );
+    }
+
+    #[test]
+    fn from_env_assignment() {
+        assert_eq!(
+            NearGas::from_env_assignment("NEAR_GAS=30 Tgas", "NEAR_GAS"),
+            Ok(NearGas::from_tgas(30))
+        );
+        assert_eq!(
+            NearGas::from_env_assignment(" NEAR_GAS = 1.5 Ggas", "NEAR_GAS"),
+            Ok(NearGas::from_gas(1_500_000_000))
+        );
+        assert_eq!(
+            NearGas::from_env_assignment("NEAR_GAS=30 Pgas", "NEAR_GAS"),
+            Err(NearGasError::incorrect_unit("30 Pgas"))
+        );
+        for data in ["OTHER_GAS=30 Tgas", "NEAR_GAS 30 Tgas", "=30 Tgas"] {
+            assert_eq!(
+                NearGas::from_env_assignment(data, "NEAR_GAS"),
+                Err(NearGasError::InvalidAssignment {
+                    input: data.to_owned(),
+                    key: "NEAR_GAS".to_owned(),
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn parse_const() {
+        const TGAS: NearGas = NearGas::parse_const("5 Tgas");
+        assert_eq!(TGAS, NearGas::from_tgas(5));
+        for (data, expected) in [
+            ("1.5 Tgas", NearGas::from_ggas(1500)),
+            (" 10TeraGas ", NearGas::from_tgas(10)),
+            ("0.000000000001 tgas", NearGas::from_gas(1)),
+            ("100 Ggas", NearGas::from_ggas(100)),
+            ("1.5 GIGAGAS", NearGas::from_gas(1_500_000_000)),
+            ("42 gas", NearGas::from_gas(42)),
+            ("18446744073709551615 gas", NearGas::MAX),
+        ] {
+            assert_eq!(NearGas::parse_const(data), expected, "data: {}", data);
+            assert_eq!(NearGas::from_str(data), Ok(expected), "data: {}", data);
+        }
+    }
+
+    #[test]
+    fn parse_const_invalid() {
+        for data in [
+            "5 Pgas",
+            "5",
+            "Tgas",
+            "1.1.1 Tgas",
+            "1. Tgas",
+            ".5 Tgas",
+            "-1 Tgas",
+            "1.5 gas",
+            "0.0000000000001 Tgas",
+            "18446744073709551616 gas",
+            "18446744073709551619 gas",
+            "184467440737095516150 gas",
+            "18446745 Tgas",
+        ] {
+            assert!(
+                std::panic::catch_unwind(|| NearGas::parse_const(data)).is_err(),
+                "data: {}",
+                data
+            );
+            assert!(NearGas::from_str(data).is_err(), "data: {}", data);
+        }
+    }
+
+    #[test]
+    fn parse_const_agrees_with_from_str() {
+        let numbers = [
+            "0",
+            "5",
+            "007",
+            "1.5",
+            "0.000000001",
+            "0.000000000001",
+            "0.0000000000001",
+            "18446744",
+            "18446744.073709551615",
+            "18446745",
+            "18446744073709551615",
+            "18446744073709551616",
+            "",
+            ".5",
+            "1.",
+            "1.1.1",
+            "-1",
+            "1e3",
+            "1 5",
+            "x",
+        ];
+        let units = [
+            "Tgas", "tgas", "TeraGas", "T", "t", "Ggas", "GIGAGAS", "G", "gas", "GAS", "Pgas",
+            "Tgass", "",
+        ];
+        for number in numbers {
+            for unit in units {
+                for separator in ["", " ", "\t"] {
+                    let data = format!(" {}{}{} ", number, separator, unit);
+                    let parsed_const =
+                        std::panic::catch_unwind(|| NearGas::parse_const(&data)).ok();
+                    assert_eq!(
+                        parsed_const,
+                        NearGas::from_str(&data).ok(),
+                        "data: {:?}",
+                        data
+                    );
+                }
+            }
+        }
+
+        // Documented exceptions: accepted by `FromStr` only.
+        for data in ["+5 Tgas", "5 Tera_Gas", "Tgas 5", "5\u{a0}Tgas"] {
+            assert!(NearGas::from_str(data).is_ok(), "data: {:?}", data);
+            assert!(
+                std::panic::catch_unwind(|| NearGas::parse_const(data)).is_err(),
+                "data: {:?}",
+                data
+            );
+        }
+    }
+
+    #[test]
+    fn near_gas_from_str_short_units() {
+        for (data, expected) in [
+            ("5 T", NearGas::from_tgas(5)),
+            ("1.5T", NearGas::from_ggas(1500)),
+            ("5 t", NearGas::from_tgas(5)),
+            ("500 G", NearGas::from_ggas(500)),
+            ("0.5g", NearGas::from_gas(500_000_000)),
+        ] {
+            assert_eq!(NearGas::from_str(data), Ok(expected), "data: {}", data);
+            assert_eq!(NearGas::parse_const(data), expected, "data: {}", data);
+        }
+        for gas in [NearGas::from_ggas(300), NearGas::from_ggas(1500)] {
+            assert_eq!(NearGas::from_str(&gas.to_si_string()), Ok(gas));
+        }
+        for data in ["5 M", "5 Tg", "5 TT"] {
+            assert_eq!(
+                NearGas::from_str(data),
+                Err(NearGasError::incorrect_unit(data))
+            );
+        }
+    }
+
+    #[test]
+    fn expect_from_str() {
+        assert_eq!(
+            NearGas::expect_from_str("1.5 Ggas"),
+            NearGas::from_gas(1_500_000_000)
+        );
+        let panic = std::panic::catch_unwind(|| NearGas::expect_from_str("30 Pgas")).unwrap_err();
+        let message = panic.downcast_ref::<String>().unwrap();
+        assert!(message.contains("\"30 Pgas\""), "message: {}", message);
+    }
+
+    #[test]
+    fn near_gas_from_str_empty_number() {
+        for data in [" Tgas", "Tgas", "   Ggas  ", "Tgas \t", "\tgas"] {
+            assert_eq!(
+                NearGas::from_str(data),
+                Err(NearGasError::IncorrectNumber(
+                    DecimalNumberParsingError::EmptyInput
+                )),
+                "data: {:?}",
+                data
+            );
+        }
+        assert_eq!(
+            NearGas::from_str("+ Tgas"),
+            Err(NearGasError::IncorrectNumber(
+                DecimalNumberParsingError::InvalidNumber("+".to_owned())
+            ))
+        );
+        assert_eq!(
+            NearGas::from_str_spanned("  Tgas"),
+            Err((
+                NearGasError::IncorrectNumber(DecimalNumberParsingError::EmptyInput),
+                2..2
+            ))
+        );
+    }
 }
@@ -0,0 +1,92 @@
+use crate::NearGas;
+
+/// Sums up gas, panicking on overflow like the standard integer `Sum` implementations.
+/// Use [`NearGas::checked_sum_indexed`] to detect overflow.
+///
+/// Together with the standard `Sum` implementation for `Result`, this also allows fallible
+/// aggregation that stops at the first error.
+///
+/// # Examples
+/// ```
+/// use near_gas::{NearGas, NearGasError};
+///
+/// let total: NearGas = [NearGas::from_tgas(1), NearGas::from_tgas(2)].into_iter().sum();
+/// assert_eq!(total, NearGas::from_tgas(3));
+///
+/// let total: Result<NearGas, NearGasError> =
+///     ["1 Tgas", "500 Ggas"].iter().map(|s| s.parse::<NearGas>()).sum();
+/// assert_eq!(total, Ok(NearGas::from_ggas(1500)));
+/// ```
+impl std::iter::Sum for NearGas {
+    fn sum<I: Iterator<Item = NearGas>>(iter: I) -> Self {
+        iter.fold(NearGas::from_gas(0), |total, gas| {
+            total
+                .checked_add(gas)
+                .expect("attempt to sum gas with overflow")
+        })
+    }
+}
+
+impl<'a> std::iter::Sum<&'a NearGas> for NearGas {
+    fn sum<I: Iterator<Item = &'a NearGas>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{DecimalNumberParsingError, NearGas, NearGasError};
+
+    #[test]
+    fn sum_gas() {
+        let values = [NearGas::from_tgas(1), NearGas::from_ggas(500)];
+        assert_eq!(values.iter().sum::<NearGas>(), NearGas::from_ggas(1500));
+        assert_eq!(
+            values.into_iter().sum::<NearGas>(),
+            NearGas::from_ggas(1500)
+        );
+        assert_eq!(
+            std::iter::empty::<NearGas>().sum::<NearGas>(),
+            NearGas::from_gas(0)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to sum gas with overflow")]
+    fn sum_gas_overflow() {
+        let _ = [NearGas::MAX, NearGas::from_gas(1)].iter().sum::<NearGas>();
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to sum gas with overflow")]
+    fn sum_result_overflow() {
+        let _ = [Ok(NearGas::MAX), Ok(NearGas::MAX)]
+            .into_iter()
+            .sum::<Result<NearGas, NearGasError>>();
+    }
+
+    #[test]
+    fn sum_result_short_circuits() {
+        let mut parsed = 0;
+        let total = ["1 Tgas", "1.x Tgas", "5 Tgas"]
+            .iter()
+            .map(|s| {
+                parsed += 1;
+                s.parse::<NearGas>()
+            })
+            .sum::<Result<NearGas, NearGasError>>();
+        assert_eq!(
+            total,
+            Err(NearGasError::IncorrectNumber(
+                DecimalNumberParsingError::InvalidNumber("1.x".to_owned())
+            ))
+        );
+        assert_eq!(parsed, 2);
+
+        let total = ["1 Tgas", "5 Tgas"]
+            .iter()
+            .map(|s| s.parse::<NearGas>())
+            .sum::<Result<NearGas, NearGasError>>();
+        assert_eq!(total, Ok(NearGas::from_tgas(6)));
+    }
+}
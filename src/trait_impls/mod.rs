@@ -1,10 +1,18 @@
+mod as_ref;
 #[cfg(feature = "borsh")]
 mod borsh;
+mod bytes;
+mod debug;
 mod display;
 mod from_str;
 #[cfg(feature = "interactive-clap")]
 mod interactive_clap;
+mod iter;
+#[cfg(feature = "near-token")]
+mod near_token;
 #[cfg(feature = "schemars")]
 mod schemars;
 #[cfg(feature = "serde")]
 mod serde;
+#[cfg(feature = "serde_json")]
+mod serde_json;
@@ -0,0 +1,46 @@
+use near_token::NearToken;
+
+use crate::NearGas;
+
+impl NearGas {
+    /// Returns `true` if burning this amount of gas at `price` (per gas) costs at least `threshold`.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// use near_token::NearToken;
+    ///
+    /// let price = NearToken::from_yoctonear(100_000_000);
+    /// assert!(NearGas::from_tgas(300).costs_at_least(price, NearToken::from_millinear(30)));
+    /// assert!(!NearGas::from_tgas(300).costs_at_least(price, NearToken::from_millinear(31)));
+    /// ```
+    pub fn costs_at_least(self, price: NearToken, threshold: NearToken) -> bool {
+        match (self.as_gas() as u128).checked_mul(price.as_yoctonear()) {
+            Some(cost) => cost >= threshold.as_yoctonear(),
+            // The cost exceeds any representable amount of tokens.
+            None => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use near_token::NearToken;
+
+    use crate::NearGas;
+
+    #[test]
+    fn costs_at_least() {
+        // 1 Tgas costs 0.0001 NEAR at the minimum gas price.
+        let price = NearToken::from_yoctonear(crate::MIN_GAS_PRICE_YOCTO);
+        let gas = NearGas::from_tgas(30);
+        assert!(gas.costs_at_least(price, NearToken::from_yoctonear(0)));
+        assert!(gas.costs_at_least(price, NearToken::from_millinear(3)));
+        assert!(!gas.costs_at_least(price, NearToken::from_yoctonear(3 * 10u128.pow(21) + 1)));
+        assert!(!NearGas::from_gas(0).costs_at_least(price, NearToken::from_yoctonear(1)));
+        assert!(NearGas::MAX.costs_at_least(
+            NearToken::from_yoctonear(u128::MAX),
+            NearToken::from_yoctonear(u128::MAX)
+        ));
+    }
+}
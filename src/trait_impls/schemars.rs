@@ -13,3 +13,55 @@ impl schemars::JsonSchema for NearGas {
         String::json_schema(gen)
     }
 }
+
+impl NearGas {
+    /// Alternative JSON schema that describes `NearGas` as an integer number of whole gas
+    /// bounded by `0..=u64::MAX`, instead of the default string representation.
+    ///
+    /// Intended to be used with `#[schemars(schema_with = "near_gas::NearGas::integer_json_schema")]`.
+    pub fn integer_json_schema(
+        _gen: &mut schemars::gen::SchemaGenerator,
+    ) -> schemars::schema::Schema {
+        use schemars::schema::{InstanceType, Metadata, NumberValidation, SchemaObject};
+
+        SchemaObject {
+            metadata: Some(Box::new(Metadata {
+                description: Some("Amount of gas, in whole gas units".to_owned()),
+                ..Default::default()
+            })),
+            instance_type: Some(InstanceType::Integer.into()),
+            format: Some("uint64".to_owned()),
+            number: Some(Box::new(NumberValidation {
+                minimum: Some(0.0),
+                // `u64::MAX` is not representable as `f64`, but `< 2^64` is exactly `<= u64::MAX`.
+                exclusive_maximum: Some(18_446_744_073_709_551_616.0),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use schemars::schema::{InstanceType, Schema, SingleOrVec};
+
+    use crate::NearGas;
+
+    #[test]
+    fn integer_json_schema() {
+        let mut gen = schemars::gen::SchemaGenerator::default();
+        let Schema::Object(schema) = NearGas::integer_json_schema(&mut gen) else {
+            panic!("expected a schema object");
+        };
+        assert_eq!(
+            schema.instance_type,
+            Some(SingleOrVec::Single(Box::new(InstanceType::Integer)))
+        );
+        let number = schema.number.unwrap();
+        assert_eq!(number.minimum, Some(0.0));
+        assert_eq!(number.maximum, None);
+        assert_eq!(number.exclusive_maximum, Some(2f64.powi(64)));
+    }
+}
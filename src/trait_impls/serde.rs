@@ -2,17 +2,24 @@ use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::NearGas;
 
+/// Number of decimal digits of `u64::MAX` (18446744073709551615).
+const U64_MAX_DIGITS: usize = 20;
+const _: () = assert!(u64::MAX.ilog10() as usize + 1 == U64_MAX_DIGITS);
+
 impl Serialize for NearGas {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
         use serde::ser::Error;
-        let mut buf = [0u8; 20];
+        // The explicit `u64` makes widening the inner type a compile error rather than
+        // silently outgrowing the buffer, which fits exactly the 20 digits of `u64::MAX`.
+        let inner: u64 = self.inner;
+        let mut buf = [0u8; U64_MAX_DIGITS];
         let remainder = {
             use std::io::Write;
             let mut w: &mut [u8] = &mut buf;
-            write!(w, "{}", self.inner)
+            write!(w, "{}", inner)
                 .map_err(|err| Error::custom(format!("Failed to serialize: {}", err)))?;
             w.len()
         };
@@ -20,20 +27,92 @@ impl Serialize for NearGas {
 
         let s = std::str::from_utf8(&buf[..len])
             .map_err(|err| Error::custom(format!("Failed to serialize: {}", err)))?;
+        debug_assert_eq!(s.parse::<u64>().ok(), Some(inner));
         serializer.serialize_str(s)
     }
 }
 
+#[cfg(feature = "serde_json")]
+impl NearGas {
+    /// Checks that `self` survives a JSON round-trip through `serde_json` unchanged.
+    ///
+    /// Intended as an invariant check in tests and fuzzing of the serde layer.
+    ///
+    /// # Examples
+    /// ```
+    /// use near_gas::NearGas;
+    /// assert!(NearGas::MAX.serde_roundtrips());
+    /// ```
+    pub fn serde_roundtrips(self) -> bool {
+        serde_json::to_string(&self)
+            .and_then(|json| serde_json::from_str::<NearGas>(&json))
+            .map_or(false, |gas| gas == self)
+    }
+}
+
 impl<'de> Deserialize<'de> for NearGas {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        let s: String = Deserialize::deserialize(deserializer)?;
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(NearGasVisitor)
+        } else {
+            deserializer.deserialize_str(NearGasVisitor)
+        }
+    }
+}
+
+/// Accepts gas as a decimal string (the canonical representation), or, for loosely-typed
+/// human-readable inputs, as a non-negative integral number of gas (e.g. `300000000000` or `3e11`).
+struct NearGasVisitor;
+
+impl<'de> de::Visitor<'de> for NearGasVisitor {
+    type Value = NearGas;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a non-negative integer amount of gas, encoded as a string or a number")
+    }
+
+    fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
         s.parse::<u64>()
             .map(NearGas::from_gas)
             .map_err(|err| de::Error::custom(err.to_string()))
     }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(NearGas::from_gas(value))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        u64::try_from(value)
+            .map(NearGas::from_gas)
+            .map_err(|_| de::Error::invalid_value(de::Unexpected::Signed(value), &self))
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        // 2^64 is the smallest f64 that does not fit into u64.
+        if (0.0..18_446_744_073_709_551_616.0).contains(&value) && value.fract() == 0.0 {
+            Ok(NearGas::from_gas(value as u64))
+        } else {
+            Err(de::Error::invalid_value(
+                de::Unexpected::Float(value),
+                &self,
+            ))
+        }
+    }
 }
 
 #[cfg(test)]
@@ -54,4 +133,85 @@ mod test {
         test_json_ser(8);
         test_json_ser(0);
     }
+
+    #[test]
+    fn json_ser_max_all_digits() {
+        let ser = serde_json::to_string(&NearGas::MAX).unwrap();
+        assert_eq!(ser, "\"18446744073709551615\"");
+        assert_eq!(ser.len(), super::U64_MAX_DIGITS + 2);
+        assert_eq!(serde_json::from_str::<NearGas>(&ser).unwrap(), NearGas::MAX);
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        fn test_json_roundtrip(val: u64) {
+            let gas = NearGas::from_gas(val);
+            let json = serde_json::to_string(&gas).unwrap();
+            assert_eq!(json, format!("\"{}\"", val));
+            assert_eq!(serde_json::from_str::<NearGas>(&json).unwrap(), gas);
+            #[cfg(feature = "serde_json")]
+            assert!(gas.serde_roundtrips(), "gas: {}", val);
+        }
+
+        test_json_roundtrip(0);
+        test_json_roundtrip(u64::MAX);
+        for exp in 0..20 {
+            let power = 10u64.pow(exp);
+            test_json_roundtrip(power - 1);
+            test_json_roundtrip(power);
+        }
+        // Deterministic xorshift sequence covering values of all magnitudes.
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        for _ in 0..1000 {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            test_json_roundtrip(state >> (state % 64));
+        }
+    }
+
+    #[test]
+    fn json_de_number() {
+        for (json, expected) in [
+            ("300000000000", 300_000_000_000),
+            ("3e11", 300_000_000_000),
+            ("3.0", 3),
+            ("0", 0),
+            ("18446744073709551615", u64::MAX),
+        ] {
+            let de: NearGas = serde_json::from_str(json).unwrap();
+            assert_eq!(de, NearGas::from_gas(expected), "json: {}", json);
+        }
+    }
+
+    #[test]
+    fn json_de_invalid_number() {
+        for json in ["3.5", "-1", "-1.0", "1e20", "\"3e11\"", "\"1.5\""] {
+            assert!(
+                serde_json::from_str::<NearGas>(json).is_err(),
+                "json: {}",
+                json
+            );
+        }
+    }
+
+    #[test]
+    fn json_skip_serializing_zero() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Config {
+            #[serde(default, skip_serializing_if = "NearGas::is_zero")]
+            gas: NearGas,
+        }
+
+        let zero = Config {
+            gas: NearGas::from_gas(0),
+        };
+        assert_eq!(serde_json::to_string(&zero).unwrap(), "{}");
+        assert_eq!(serde_json::from_str::<Config>("{}").unwrap(), zero);
+
+        let non_zero = Config {
+            gas: NearGas::from_gas(5),
+        };
+        assert_eq!(serde_json::to_string(&non_zero).unwrap(), r#"{"gas":"5"}"#);
+    }
 }
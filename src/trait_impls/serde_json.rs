@@ -0,0 +1,67 @@
+use crate::{DecimalNumberParsingError, NearGas, NearGasError};
+
+/// Accepts non-negative integers and integral floats (e.g. `3e11`) that fit into `u64`.
+impl TryFrom<&serde_json::Number> for NearGas {
+    type Error = NearGasError;
+
+    fn try_from(number: &serde_json::Number) -> Result<Self, Self::Error> {
+        if let Some(gas) = number.as_u64() {
+            return Ok(NearGas::from_gas(gas));
+        }
+        match number.as_f64() {
+            // 2^64 is the smallest f64 that does not fit into u64.
+            Some(gas) if gas.fract() == 0.0 && gas >= 18_446_744_073_709_551_616.0 => {
+                Err(DecimalNumberParsingError::LongWhole(number.to_string()).into())
+            }
+            Some(gas) if gas.fract() == 0.0 && gas >= 0.0 => Ok(NearGas::from_gas(gas as u64)),
+            _ => Err(DecimalNumberParsingError::InvalidNumber(number.to_string()).into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{DecimalNumberParsingError, NearGas, NearGasError};
+
+    fn number(json: &str) -> serde_json::Number {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn try_from_number() {
+        for (json, expected) in [
+            ("0", 0),
+            ("300000000000000", 300_000_000_000_000),
+            ("18446744073709551615", u64::MAX),
+            ("3e11", 300_000_000_000),
+            ("3.0", 3),
+        ] {
+            assert_eq!(
+                NearGas::try_from(&number(json)),
+                Ok(NearGas::from_gas(expected)),
+                "json: {}",
+                json
+            );
+        }
+    }
+
+    #[test]
+    fn try_from_invalid_number() {
+        for json in ["3.5", "-1", "-1.0"] {
+            assert_eq!(
+                NearGas::try_from(&number(json)),
+                Err(NearGasError::IncorrectNumber(
+                    DecimalNumberParsingError::InvalidNumber(number(json).to_string())
+                )),
+                "json: {}",
+                json
+            );
+        }
+        assert_eq!(
+            NearGas::try_from(&number("1e20")),
+            Err(NearGasError::IncorrectNumber(
+                DecimalNumberParsingError::LongWhole(number("1e20").to_string())
+            ))
+        );
+    }
+}
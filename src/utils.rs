@@ -4,14 +4,28 @@
 ///
 /// If the fractional part is longer than several zeros in the prefix, it will return the error `DecimalNumberParsingError::LongFractional`.
 ///
+/// If the string slice is empty or consists of whitespace only, it will return the error `DecimalNumberParsingError::EmptyInput`.
+///
 /// If the string slice has invalid chars, it will return the error `DecimalNumberParsingError::InvalidNumber`.
+/// A single leading `+` sign is accepted, any other non-digit chars (e.g. `inf` or `NaN`) are invalid.
 ///
 /// If the whole part of the number has a value more than the `u64` maximum value, it will return the error `DecimalNumberParsingError::LongWhole`.
 pub(crate) fn parse_decimal_number(
     s: &str,
     pref_const: u64,
 ) -> Result<u64, DecimalNumberParsingError> {
-    let (int, fract) = if let Some((whole, fractional)) = s.trim().split_once('.') {
+    let number = s.trim();
+    if number.is_empty() {
+        return Err(DecimalNumberParsingError::EmptyInput);
+    }
+    // A single leading `+` is allowed. Anything else besides digits and dots is rejected explicitly,
+    // including signs in the fractional part (which `u64::from_str` would accept)
+    // and float artifacts like `inf` or `NaN`.
+    let number = number.strip_prefix('+').unwrap_or(number);
+    if !number.bytes().all(|b| b.is_ascii_digit() || b == b'.') {
+        return Err(DecimalNumberParsingError::InvalidNumber(s.to_owned()));
+    }
+    let (int, fract) = if let Some((whole, fractional)) = number.split_once('.') {
         let int: u64 = whole
             .parse()
             .map_err(|_| DecimalNumberParsingError::InvalidNumber(s.to_owned()))?;
@@ -34,7 +48,7 @@ pub(crate) fn parse_decimal_number(
             .ok_or_else(|| DecimalNumberParsingError::LongFractional(fractional.to_owned()))?;
         (int, fract)
     } else {
-        let int: u64 = s
+        let int: u64 = number
             .parse()
             .map_err(|_| DecimalNumberParsingError::InvalidNumber(s.to_owned()))?;
         (int, 0)
@@ -48,11 +62,74 @@ pub(crate) fn parse_decimal_number(
     Ok(result)
 }
 
+/// Parsing decimal numbers like `parse_decimal_number`, but instead of returning
+/// the error `DecimalNumberParsingError::LongFractional`, the fractional digits beyond the prefix precision are dropped.
+/// The returned flag reports whether any of the dropped digits was non-zero.
+pub(crate) fn parse_decimal_number_lossy(
+    s: &str,
+    pref_const: u64,
+) -> Result<(u64, bool), DecimalNumberParsingError> {
+    let precision = pref_const.ilog10() as usize;
+    if let Some((whole, fractional)) = s.trim().split_once('.') {
+        if fractional.len() > precision && fractional.is_ascii() {
+            let (kept, dropped) = fractional.split_at(precision);
+            if !dropped.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(DecimalNumberParsingError::InvalidNumber(s.to_owned()));
+            }
+            let value = if kept.is_empty() {
+                parse_decimal_number(whole, pref_const)
+            } else {
+                parse_decimal_number(&format!("{}.{}", whole, kept), pref_const)
+            }
+            .map_err(|err| match err {
+                // The number as a whole is not empty, only its whole part is (e.g. ".5").
+                DecimalNumberParsingError::InvalidNumber(_)
+                | DecimalNumberParsingError::EmptyInput => {
+                    DecimalNumberParsingError::InvalidNumber(s.to_owned())
+                }
+                err => err,
+            })?;
+            return Ok((value, dropped.bytes().any(|b| b != b'0')));
+        }
+    }
+    parse_decimal_number(s, pref_const).map(|value| (value, false))
+}
+
+/// Formatting `u64` value as a decimal number with the given metric prefix.
+/// It is the inverse of `parse_decimal_number`: the fractional part is written without trailing zeros,
+/// and it is omitted entirely when it is zero.
+pub(crate) fn format_decimal_number(value: u64, pref_const: u64) -> String {
+    let whole = value / pref_const;
+    let fractional = value % pref_const;
+    if fractional == 0 {
+        return whole.to_string();
+    }
+    let fractional = format!(
+        "{:0width$}",
+        fractional,
+        width = pref_const.ilog10() as usize
+    );
+    format!("{}.{}", whole, fractional.trim_end_matches('0'))
+}
+
+/// Greatest common divisor of two `u64` values (Euclidean algorithm).
+pub(crate) const fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let remainder = a % b;
+        a = b;
+        b = remainder;
+    }
+    a
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum DecimalNumberParsingError {
     InvalidNumber(String),
     LongWhole(String),
     LongFractional(String),
+    /// The number is missing, e.g. `" Tgas"`.
+    EmptyInput,
 }
 
 impl std::error::Error for DecimalNumberParsingError {
@@ -81,6 +158,7 @@ impl std::fmt::Display for DecimalNumberParsingError {
             DecimalNumberParsingError::LongFractional(s) => {
                 write!(f, "Long fractional part: {}", s)
             }
+            DecimalNumberParsingError::EmptyInput => write!(f, "Empty number"),
         }
     }
 }
@@ -147,6 +225,75 @@ mod tests {
         );
     }
 
+    #[test]
+    fn empty_input() {
+        let prefix = 10u64.pow(9);
+        for num in ["", " ", "\t \n"] {
+            assert_eq!(
+                parse_decimal_number(num, prefix),
+                Err(DecimalNumberParsingError::EmptyInput)
+            );
+            assert_eq!(
+                parse_decimal_number_lossy(num, prefix),
+                Err(DecimalNumberParsingError::EmptyInput)
+            );
+        }
+        assert_eq!(
+            parse_decimal_number(".", prefix),
+            Err(DecimalNumberParsingError::InvalidNumber(".".to_owned()))
+        );
+    }
+
+    #[test]
+    fn plus_sign() {
+        let prefix = 10u64.pow(9);
+        assert_eq!(parse_decimal_number("+5", prefix), Ok(5 * prefix));
+        assert_eq!(parse_decimal_number(" +1.5", prefix), Ok(1_500_000_000));
+        for num in ["++5", "+-5", "-5", "5.+5", "5.-5", "+"] {
+            assert_eq!(
+                parse_decimal_number(num, prefix),
+                Err(DecimalNumberParsingError::InvalidNumber(num.to_owned()))
+            );
+        }
+    }
+
+    #[test]
+    fn parse_lossy_test() {
+        for (expected_value, str_value, precision) in TEST {
+            assert_eq!(
+                parse_decimal_number_lossy(str_value, precision),
+                Ok((expected_value, false))
+            );
+        }
+        assert_eq!(
+            parse_decimal_number_lossy("1.23456", 10000),
+            Ok((12345, true))
+        );
+        assert_eq!(
+            parse_decimal_number_lossy("1.23450", 10000),
+            Ok((12345, false))
+        );
+        assert_eq!(parse_decimal_number_lossy("1.9", 1), Ok((1, true)));
+        assert_eq!(
+            parse_decimal_number_lossy("1.2345h", 10000),
+            Err(DecimalNumberParsingError::InvalidNumber(
+                "1.2345h".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn float_artifacts() {
+        for num in [
+            "inf", "INF", "-inf", "infinity", "NaN", "nan", "1e3", "1.5e3",
+        ] {
+            assert_eq!(
+                parse_decimal_number(num, 10u64.pow(12)),
+                Err(DecimalNumberParsingError::InvalidNumber(num.to_owned()))
+            );
+        }
+    }
+
     #[test]
     fn max_long_fract() {
         let max_data = 10u64.pow(17) + 1;
@@ -181,6 +328,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn format_test() {
+        for (value, str_value, precision) in TEST {
+            let formatted = format_decimal_number(value, precision);
+            assert_eq!(
+                parse_decimal_number(&formatted, precision).unwrap(),
+                value,
+                "{} formatted as {}",
+                str_value,
+                formatted
+            );
+        }
+        assert_eq!(format_decimal_number(0, 10u64.pow(9)), "0");
+        assert_eq!(format_decimal_number(1, 10u64.pow(9)), "0.000000001");
+        assert_eq!(format_decimal_number(1_500_000_000, 10u64.pow(9)), "1.5");
+        assert_eq!(format_decimal_number(42, 1), "42");
+    }
+
     #[test]
     fn test() {
         let data = "1.000000000000000000000000000000000000001";
@@ -0,0 +1,5 @@
+near_gas::gas_constants! {
+    CALL_GAS = "30 Pgas",
+}
+
+fn main() {}